@@ -0,0 +1,129 @@
+use super::*;
+
+/// A builder for constructing a complete Modinfo with typed, fluent setters
+///
+/// Unlike the string-keyed `Modinfo::set_value_for`, a typo in a field name is a compile error,
+/// and `build` checks the required fields (author, description, name and version) are set.
+///
+/// # Example
+///
+/// ```rust
+/// use modinfo::Modinfo;
+///
+/// let modinfo = Modinfo::builder()
+///     .name("SomeMod")
+///     .version("1.2.3")
+///     .compat("A21")
+///     .author("Me")
+///     .description("Does things")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(modinfo.get_version(), &semver::Version::new(1, 2, 3));
+/// assert_eq!(modinfo.display_name(), Some("Some Mod"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ModinfoBuilder {
+    modinfo: Modinfo,
+    version: Option<String>,
+}
+
+impl ModinfoBuilder {
+    /// Create a new builder for a Modinfo targeting `ModinfoVersion::latest()`
+    pub fn new() -> Self {
+        ModinfoBuilder::default()
+    }
+
+    /// Sets the ModInfo.xml format to write (default: `ModinfoVersion::latest()`)
+    pub fn modinfo_version(mut self, version: ModinfoVersion) -> Self {
+        self.modinfo.set_modinfo_version(version);
+        self
+    }
+
+    /// Sets the internal name (required)
+    pub fn name(mut self, name: &str) -> Self {
+        self.modinfo.set_value_for("name", name);
+        self
+    }
+
+    /// Sets the display name (V2 only), derived from `name` if not set
+    pub fn display_name(mut self, display_name: &str) -> Self {
+        self.modinfo.set_value_for("display_name", display_name);
+        self
+    }
+
+    /// Sets the modlet version (required), parsed leniently by `build`
+    pub fn version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_owned());
+        self
+    }
+
+    /// Sets the compat value of the version, e.g. `A21`
+    pub fn compat(mut self, compat: &str) -> Self {
+        self.modinfo.set_value_for("compat", compat);
+        self
+    }
+
+    /// Sets the description (required)
+    pub fn description(mut self, description: &str) -> Self {
+        self.modinfo.set_value_for("description", description);
+        self
+    }
+
+    /// Sets the author (required)
+    pub fn author(mut self, author: &str) -> Self {
+        self.modinfo.set_value_for("author", author);
+        self
+    }
+
+    /// Sets the website (V2 only)
+    pub fn website(mut self, website: &str) -> Self {
+        self.modinfo.set_value_for("website", website);
+        self
+    }
+
+    /// Sets the changelog (V2 only)
+    pub fn changelog(mut self, changelog: &str) -> Self {
+        self.modinfo.set_value_for("changelog", changelog);
+        self
+    }
+
+    /// Sets the load-order priority (V2 only)
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.modinfo.set_priority(Some(priority));
+        self
+    }
+
+    /// Sets whether mod managers should enable the modlet (V2 only)
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.modinfo.set_enabled(Some(enabled));
+        self
+    }
+
+    /// Builds the Modinfo, checking the required fields as `Modinfo::validate` does
+    ///
+    /// ## Possible ModinfoError
+    ///
+    /// * `ModinfoError::InvalidVersionString` - the version could not be parsed (no fallback version is used)
+    /// * `ModinfoError::NoModinfoAuthor` - no author set
+    /// * `ModinfoError::NoModinfoDescription` - no description set
+    /// * `ModinfoError::NoModinfoName` - no name set
+    /// * `ModinfoError::NoModinfoVersion` - no version set
+    pub fn build(self) -> Result<Modinfo, ModinfoError> {
+        let mut modinfo = self.modinfo;
+
+        match self.version {
+            Some(version) => modinfo.try_set_value_for("version", &version)?,
+            None => modinfo.meta.version_missing = true,
+        }
+        if modinfo.display_name.value.is_none() {
+            if let Some(name) = modinfo.name() {
+                modinfo.display_name.value = Some(name.to_case(Case::Title).into());
+                modinfo.meta.display_name_derived = true;
+            }
+        }
+        modinfo.validate()?;
+
+        Ok(modinfo)
+    }
+}
@@ -0,0 +1,113 @@
+use super::*;
+use std::cmp::Ordering;
+
+/// The release channel of a 7 Days to Die game version
+///
+/// `Alpha` builds (`A20`, `A21.2`) predate the stable 1.0 release, which is versioned
+/// as `V1`, `V1.1`, etc.  Stable versions always outrank Alpha versions.
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CompatChannel {
+    Alpha,
+    Stable,
+}
+
+impl CompatChannel {
+    fn prefix(&self) -> char {
+        match self {
+            CompatChannel::Alpha => 'A',
+            CompatChannel::Stable => 'V',
+        }
+    }
+}
+
+/// A parsed game version as used by the `compat` attribute of the `<Version>` tag
+///
+/// Versions order by channel, then major, then minor. This ordering is structural, so a missing
+/// minor (`A21`) sorts before `A21.0`; use `compat_cmp` to compare by the game version targeted.
+///
+/// # Example
+///
+/// ```rust
+/// use modinfo::{CompatChannel, CompatVersion};
+/// use std::str::FromStr;
+///
+/// let compat = CompatVersion::from_str("A21.2").unwrap();
+///
+/// assert_eq!(compat.channel, CompatChannel::Alpha);
+/// assert_eq!(compat.major, 21);
+/// assert_eq!(compat.minor, Some(2));
+/// assert_eq!(compat.to_string(), "A21.2");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CompatVersion {
+    pub channel: CompatChannel,
+    pub major: u32,
+    pub minor: Option<u32>,
+}
+
+impl fmt::Display for CompatVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.minor {
+            Some(minor) => write!(f, "{}{}.{}", self.channel.prefix(), self.major, minor),
+            None => write!(f, "{}{}", self.channel.prefix(), self.major),
+        }
+    }
+}
+
+impl FromStr for CompatVersion {
+    type Err = ModinfoError;
+
+    fn from_str(compat: &str) -> Result<Self, Self::Err> {
+        let invalid = || ModinfoError::InvalidCompat(compat.to_owned());
+        let trimmed = compat.trim();
+
+        let mut chars = trimmed.chars();
+        let channel = match chars.next().map(|c| c.to_ascii_uppercase()) {
+            Some('A') => CompatChannel::Alpha,
+            Some('V') => CompatChannel::Stable,
+            _ => return Err(invalid()),
+        };
+
+        let (major, minor) = match chars.as_str().split_once('.') {
+            Some((major, minor)) => (major, Some(minor)),
+            None => (chars.as_str(), None),
+        };
+
+        let parse_part = |part: &str| {
+            if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            part.parse::<u32>().map_err(|_| invalid())
+        };
+
+        Ok(CompatVersion {
+            channel,
+            major: parse_part(major)?,
+            minor: minor.map(parse_part).transpose()?,
+        })
+    }
+}
+
+/// Compares two compat strings by the game version they target
+///
+/// A missing minor version counts as `0`, so `A21` and `A21.0` compare equal.
+/// Returns `None` if either string cannot be parsed as a `CompatVersion`
+///
+/// ```rust
+/// use modinfo::compat_cmp;
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(compat_cmp("A21", "A20"), Some(Ordering::Greater));
+/// assert_eq!(compat_cmp("A99", "V1"), Some(Ordering::Less));
+/// assert_eq!(compat_cmp("A21", "A21.0"), Some(Ordering::Equal));
+/// assert_eq!(compat_cmp("A21", "banana"), None);
+/// ```
+pub fn compat_cmp(a: &str, b: &str) -> Option<Ordering> {
+    let game_version = |compat: &str| {
+        CompatVersion::from_str(compat)
+            .ok()
+            .map(|compat| (compat.channel, compat.major, compat.minor.unwrap_or(0)))
+    };
+
+    Some(game_version(a)?.cmp(&game_version(b)?))
+}
@@ -0,0 +1,72 @@
+use super::*;
+
+/// A field of a Modinfo, e.g. for choosing which fields `parse_with_options` requires
+///
+/// # Example
+///
+/// ```rust
+/// use modinfo::ModinfoField;
+///
+/// assert_eq!(ModinfoField::DisplayName.as_str(), "display_name");
+/// assert_eq!(ModinfoField::DisplayName.to_string(), "DisplayName");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ModinfoField {
+    Name,
+    DisplayName,
+    Version,
+    Description,
+    Author,
+    Website,
+    Changelog,
+    Priority,
+    EnableMod,
+}
+
+impl ModinfoField {
+    /// Every field, in the canonical order they are written
+    pub const ALL: [ModinfoField; 9] = [
+        ModinfoField::Name,
+        ModinfoField::DisplayName,
+        ModinfoField::Version,
+        ModinfoField::Description,
+        ModinfoField::Author,
+        ModinfoField::Website,
+        ModinfoField::Changelog,
+        ModinfoField::Priority,
+        ModinfoField::EnableMod,
+    ];
+
+    /// The field name as accepted by `get_value_for` and `set_value_for`
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            ModinfoField::Name => "name",
+            ModinfoField::DisplayName => "display_name",
+            ModinfoField::Version => "version",
+            ModinfoField::Description => "description",
+            ModinfoField::Author => "author",
+            ModinfoField::Website => "website",
+            ModinfoField::Changelog => "changelog",
+            ModinfoField::Priority => "priority",
+            ModinfoField::EnableMod => "enable_mod",
+        }
+    }
+
+    /// The error reported when this field is required but missing
+    pub(crate) fn missing_error(&self) -> ModinfoError {
+        match self {
+            ModinfoField::Author => ModinfoError::NoModinfoAuthor,
+            ModinfoField::Description => ModinfoError::NoModinfoDescription,
+            ModinfoField::Name => ModinfoError::NoModinfoName,
+            ModinfoField::Version => ModinfoError::NoModinfoVersion,
+            field => ModinfoError::NoModinfoField(*field),
+        }
+    }
+}
+
+impl fmt::Display for ModinfoField {
+    /// Writes the XML tag name of the field, e.g. `DisplayName`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str().to_case(Case::Pascal))
+    }
+}
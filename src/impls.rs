@@ -1,176 +1,2078 @@
 use super::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 impl Modinfo {
     /// Create a new Modinfo instance with default values
+    ///
+    /// New instances target `ModinfoVersion::latest()`
     pub fn new() -> Self {
         Modinfo::default()
     }
 
-    /// Write the Modinfo to a file
-    /// uses `modinfo_version` to determine which format to use
-    pub fn write(&self, file: Option<&Path>) -> Result<(), ModinfoError> {
-        match file {
-            Some(path) => {
-                fs::write(path, self.to_string())?;
-            }
-            None => {
-                fs::write(self.meta.path.clone(), self.to_string())?;
+    /// Create a new Modinfo instance with default values, targeting the given ModInfo.xml format
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoVersion};
+    ///
+    /// let modinfo = Modinfo::new_with_version(ModinfoVersion::V1);
+    ///
+    /// assert_eq!(modinfo.get_modinfo_version(), ModinfoVersion::V1);
+    /// ```
+    pub fn new_with_version(version: ModinfoVersion) -> Self {
+        let mut modinfo = Modinfo::default();
+        modinfo.meta.version = version;
+        modinfo
+    }
+
+    /// Parses a ModInfo.xml string using the given `ParseOptions`
+    ///
+    /// `Modinfo::from_str` is equivalent to calling this with `ParseOptions::default()`
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ParseOptions};
+    ///
+    /// let xml = r#"<ModInfo><Name>  SomeMod  </Name></ModInfo>"#;
+    /// let options = ParseOptions { trim_text: false, ..Default::default() };
+    /// let modinfo = Modinfo::from_str_with_options(xml, &options).unwrap();
+    ///
+    /// assert_eq!(modinfo.name(), Some("  SomeMod  "));
+    /// ```
+    pub fn from_str_with_options(xml: &str, options: &ParseOptions) -> Result<Self, ModinfoError> {
+        check_size(xml.len(), options.max_total_len)?;
+
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(options.trim_text);
+
+        let mut modinfo = Modinfo::from_reader_with_options(reader, options)?;
+        modinfo.meta.line_ending = detect_line_ending(xml);
+
+        Ok(modinfo)
+    }
+
+    /// Create a new Modinfo for scaffolding a mod, with every required field filled in
+    ///
+    /// `display_name` is derived from `name` (title-cased), the description is a placeholder to be
+    /// replaced, and the version is `0.1.0`. The result passes `validate`.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let modinfo = Modinfo::scaffold("MyMod", "Me");
+    ///
+    /// assert!(modinfo.validate().is_ok());
+    /// assert_eq!(modinfo.display_name(), Some("My Mod"));
+    /// ```
+    pub fn scaffold(name: &str, author: &str) -> Self {
+        let mut modinfo = Modinfo::default();
+        modinfo.set_value_for("name", name);
+        modinfo.set_value_for("display_name", &name.to_case(Case::Title));
+        modinfo.set_value_for("author", author);
+        modinfo.set_value_for("description", "A new 7 Days to Die mod");
+        modinfo.meta.display_name_derived = true;
+        modinfo
+    }
+
+    /// Create a `ModinfoBuilder` for constructing a complete Modinfo, see `ModinfoBuilder`
+    pub fn builder() -> ModinfoBuilder {
+        ModinfoBuilder::new()
+    }
+
+    /// Builds a validated Modinfo from a map of field names to values, e.g. from a config loader
+    ///
+    /// The keys `name`, `display_name`, `version`, `compat`, `description`, `author`, `website`
+    /// and `modinfo_version` (`V1` or `V2`, default: latest) are read; any other keys are ignored.
+    /// Values are set as by `try_set_value_for`, so an invalid version is an error rather than
+    /// falling back, and the result must pass `validate`.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::collections::HashMap;
+    ///
+    /// let map = HashMap::from([
+    ///     ("name".to_owned(), "SomeMod".to_owned()),
+    ///     ("version".to_owned(), "1.0.0".to_owned()),
+    ///     ("author".to_owned(), "Me".to_owned()),
+    ///     ("description".to_owned(), "Does things".to_owned()),
+    /// ]);
+    ///
+    /// assert_eq!(Modinfo::from_map(&map).unwrap().name(), Some("SomeMod"));
+    /// ```
+    pub fn from_map(map: &HashMap<String, String>) -> Result<Modinfo, ModinfoError> {
+        let mut modinfo = match map.get("modinfo_version") {
+            Some(version) => Modinfo::new_with_version(ModinfoVersion::from_str(version)?),
+            None => Modinfo::new(),
+        };
+        // the default version doesn't count, so a map without one fails validation
+        modinfo.meta.version_missing = true;
+
+        for field in [
+            "name",
+            "display_name",
+            "version",
+            "compat",
+            "description",
+            "author",
+            "website",
+        ] {
+            if let Some(value) = map.get(field) {
+                modinfo.try_set_value_for(field, value)?;
+            }
+        }
+        modinfo.validate()?;
+
+        Ok(modinfo)
+    }
+
+    /// Checks that every required field (author, description, name and version) is present
+    ///
+    /// These are the same checks `parse` applies to a file on disk.
+    ///
+    /// ## Possible ModinfoError
+    ///
+    /// * `ModinfoError::NoModinfoAuthor` - no Author set
+    /// * `ModinfoError::NoModinfoDescription` - no Description set
+    /// * `ModinfoError::NoModinfoName` - no Name set
+    /// * `ModinfoError::NoModinfoVersion` - no Version set
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoError};
+    ///
+    /// let modinfo = Modinfo::default();
+    ///
+    /// assert!(matches!(modinfo.validate(), Err(ModinfoError::NoModinfoAuthor)));
+    /// ```
+    pub fn validate(&self) -> Result<(), ModinfoError> {
+        self.validate_required(&ParseOptions::default().required_fields)
+    }
+
+    /// Checks that each of the given `fields` has a value, reporting the first one missing
+    ///
+    /// A version always has a value, so it is only missing when a parsed document had no `<Version>`
+    /// element (and none was set since).
+    pub(crate) fn validate_required(&self, fields: &[ModinfoField]) -> Result<(), ModinfoError> {
+        let is_missing = |field: &&ModinfoField| match field {
+            ModinfoField::Version => self.meta.version_missing,
+            _ => self.get_owned_value_for(field.as_str()).is_none(),
+        };
+
+        match fields.iter().find(is_missing) {
+            Some(field) => Err(field.missing_error()),
+            None => Ok(()),
+        }
+    }
+
+    /// Renders the Modinfo as the XML that `write` would save, without touching the filesystem
+    ///
+    /// Useful for previewing changes before overwriting a modinfo.xml file
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("name", "SomeMod");
+    ///
+    /// assert!(modinfo.render().unwrap().contains(r#"<Name value="SomeMod"/>"#));
+    /// ```
+    pub fn render(&self) -> Result<String, ModinfoError> {
+        self.render_with_options(&WriteOptions::default())
+    }
+
+    /// Serializes the Modinfo, refusing to silently drop data the output format cannot hold
+    ///
+    /// V1 output has no `Website`, `DisplayName`, `Changelog`, `Priority` or `EnableMod` elements; if any of these are set
+    /// the warnings from `to_v1_lossy` are returned instead of the XML. `to_string` can still be
+    /// used to accept the loss.
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoVersion};
+    ///
+    /// let mut modinfo = Modinfo::new_with_version(ModinfoVersion::V1);
+    /// assert!(modinfo.serialize_checked().is_ok());
+    ///
+    /// modinfo.set_value_for("website", "https://example.org");
+    /// assert_eq!(
+    ///     modinfo.serialize_checked().unwrap_err(),
+    ///     vec!["website 'https://example.org' dropped".to_owned()]
+    /// );
+    /// ```
+    pub fn serialize_checked(&self) -> Result<String, Vec<String>> {
+        if self.is_v1() {
+            let (_, warnings) = self.to_v1_lossy();
+            if !warnings.is_empty() {
+                return Err(warnings);
+            }
+        }
+
+        Ok(self.to_string())
+    }
+
+    /// Serializes the Modinfo using the given `WriteOptions`, with a stable, exact output format
+    ///
+    /// The output is suitable for byte-for-byte (golden file) comparison, and is guaranteed to be:
+    ///
+    /// * a V2 XML declaration first (the parsed one, else `<?xml version="1.0" encoding="UTF-8"?>`)
+    /// * one element per line, indented by two spaces, without a trailing newline (unless `minified`)
+    /// * self-closing elements without a space before `/>`
+    /// * the `value` attribute first, followed by `compat` (on `Version`) or `lang` (on `DisplayName`)
+    /// * elements in canonical order (unless `preserve_order`), with unset `Changelog`, `Priority` and
+    ///   `EnableMod` elements omitted (and any other unset element with `omit_empty`)
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoVersion, WriteOptions};
+    ///
+    /// let mut modinfo = Modinfo::new_with_version(ModinfoVersion::V1);
+    /// modinfo.set_value_for("name", "SomeMod");
+    ///
+    /// assert_eq!(
+    ///     modinfo.to_string_exact(&WriteOptions::default()),
+    ///     "<ModInfo>\n  <Name value=\"SomeMod\"/>\n  <Version value=\"0.1.0\"/>\n  <Description value=\"\"/>\n  <Author value=\"\"/>\n</ModInfo>"
+    /// );
+    /// ```
+    pub fn to_string_exact(&self, opts: &WriteOptions) -> String {
+        self.to_string_with_options(opts)
+    }
+
+    /// Serializes the Modinfo as whitespace-normalized XML for equality comparison
+    ///
+    /// Elements are always written in canonical order on a single line, so two modinfos with the
+    /// same content produce identical output whatever their source formatting or element order.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::str::FromStr;
+    ///
+    /// let a = Modinfo::from_str(r#"<xml><Name value="SomeMod" /><Author value="Me" /></xml>"#).unwrap();
+    /// let b = Modinfo::from_str("<xml>\n  <Author value=\"Me\" />\n  <Name value=\"SomeMod\" />\n</xml>").unwrap();
+    ///
+    /// assert_eq!(a.canonical_xml(), b.canonical_xml());
+    /// ```
+    pub fn canonical_xml(&self) -> String {
+        let options = WriteOptions {
+            minified: true,
+            ..Default::default()
+        };
+
+        self.to_string_with_options(&options)
+    }
+
+    /// Write the Modinfo to a file
+    /// uses `modinfo_version` to determine which format to use
+    ///
+    /// The content written is exactly what `render` returns, and the file is written atomically,
+    /// see `write_atomic`. Missing parent directories are created first, e.g. when scaffolding a new mod folder.
+    pub fn write(&self, file: Option<&Path>) -> Result<(), ModinfoError> {
+        if let Some(dir) = file.unwrap_or(&self.meta.path).parent() {
+            if !dir.as_os_str().is_empty() {
+                fs::create_dir_all(dir)?;
+            }
+        }
+
+        self.write_atomic(file)
+    }
+
+    /// Write the Modinfo to a file like `write`, first backing up any existing file
+    ///
+    /// An existing target is copied alongside it with a `.bak` suffix (e.g. `ModInfo.xml.bak`),
+    /// replacing any previous backup. If the backup can't be made, the target is left untouched.
+    pub fn write_with_backup(&self, file: Option<&Path>) -> Result<(), ModinfoError> {
+        let path = file.unwrap_or(&self.meta.path);
+
+        if path.is_file() {
+            let mut backup = path.as_os_str().to_owned();
+            backup.push(".bak");
+            fs::copy(path, backup)?;
+        }
+
+        self.write(file)
+    }
+
+    /// Write the Modinfo to a file atomically
+    ///
+    /// The XML is first written to a temporary file in the same directory, which is then
+    /// renamed over the target, so an interrupted write never leaves a truncated modinfo.xml behind.
+    ///
+    /// When `file` is `None`, the stored file path is used.
+    ///
+    /// Returns `ModinfoError::FsDirNotFound` if the target directory does not exist (`write` creates it)
+    pub fn write_atomic(&self, file: Option<&Path>) -> Result<(), ModinfoError> {
+        let path = file.unwrap_or(&self.meta.path);
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+
+        if !dir.is_dir() {
+            return Err(ModinfoError::FsDirNotFound(dir.to_path_buf()));
+        }
+
+        // a per-call suffix, so concurrent writes (even from the same process) never share a temp file
+        static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let counter = TEMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        let file_name = path.file_name().ok_or(ModinfoError::WriteError)?;
+        let temp_path = dir.join(format!(
+            ".{}.{}.{}.tmp",
+            file_name.to_string_lossy(),
+            std::process::id(),
+            counter
+        ));
+
+        fs::write(&temp_path, self.render()?)?;
+        if let Err(err) = fs::rename(&temp_path, path) {
+            let _ = fs::remove_file(&temp_path);
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks whether the file at `path` holds exactly what `write` would save, e.g. for CI drift checks
+    ///
+    /// Insignificant whitespace (indentation, line endings and blank lines) is ignored.
+    /// Returns `Ok(false)` on any other difference, or an error if the file cannot be read.
+    pub fn matches_file(&self, path: &Path) -> Result<bool, ModinfoError> {
+        let on_disk = fs::read_to_string(path)?;
+
+        Ok(strip_insignificant_ws(&on_disk) == strip_insignificant_ws(&self.render()?))
+    }
+
+    /// Re-reads the modinfo.xml file at the stored path, replacing all values with those on disk
+    ///
+    /// The file is parsed as by `parse`. Returns `ModinfoError::FsNotFound` if no path is set or the
+    /// file no longer exists, in which case `self` is left unchanged.
+    pub fn reload(&mut self) -> Result<(), ModinfoError> {
+        if self.meta.path.as_os_str().is_empty() {
+            return Err(ModinfoError::FsNotFound);
+        }
+
+        *self = parse(&self.meta.path)?;
+
+        Ok(())
+    }
+
+    /// Collects warnings about parsed values which are suspicious but not errors, see `parse_with_warnings`
+    pub(crate) fn parse_warnings(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        let raw_version = self
+            .raw_attributes("Version")
+            .and_then(|attributes| attributes.get("value"));
+        if let Some(raw_version) = raw_version {
+            if let Some(error) = &self.meta.version_parse_error {
+                warnings.push(format!(
+                    "version '{}' could not be parsed, using '{}': {}",
+                    raw_version, self.version.value, error
+                ));
+            } else if Version::parse(raw_version).is_err() {
+                warnings.push(format!(
+                    "version '{}' is not strict semver, parsed as '{}'",
+                    raw_version, self.version.value
+                ));
+            }
+        }
+
+        if let Some(website) = self.website() {
+            if !website.starts_with("http://") && !website.starts_with("https://") {
+                warnings.push(format!("website '{}' is not a http(s) URL", website));
+            }
+        }
+
+        if self.is_v2() && self.meta.display_name_derived {
+            warnings.push(format!(
+                "display_name '{}' was derived from name",
+                self.display_name().unwrap_or_default()
+            ));
+        }
+
+        warnings
+    }
+
+    /// Validates the parsed document structure against the given ModInfo.xml format
+    ///
+    /// This is stricter than the required-field checks done by `parse`, and reports every problem found:
+    ///
+    /// * `ModinfoError::InvalidRoot` - the root element is not `<xml>` (V2) or `<ModInfo>` (V1)
+    /// * `ModinfoError::UnknownTag` - an element which is not part of the format
+    /// * `ModinfoError::NoValueAttribute` - an element without a `value`
+    ///
+    /// Only parsed documents have structure to check, so a Modinfo built in code always passes.
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoVersion};
+    /// use std::str::FromStr;
+    ///
+    /// let modinfo = Modinfo::from_str(r#"<xml><Name value="SomeMod" /><Foo value="bar" /></xml>"#).unwrap();
+    ///
+    /// assert_eq!(modinfo.validate_schema(ModinfoVersion::V2).unwrap_err().len(), 1);
+    /// ```
+    pub fn validate_schema(&self, version: ModinfoVersion) -> Result<(), Vec<ModinfoError>> {
+        let mut errors = Vec::new();
+        let (root, tags): (&str, &[&str]) = match version {
+            ModinfoVersion::V1 => ("ModInfo", &["Name", "Version", "Description", "Author"]),
+            ModinfoVersion::V2 => (
+                "xml",
+                &[
+                    "Name",
+                    "DisplayName",
+                    "Version",
+                    "Description",
+                    "Author",
+                    "Website",
+                    "Changelog",
+                    "Priority",
+                    "EnableMod",
+                ],
+            ),
+        };
+
+        if !self.meta.root_tag.is_empty() && self.meta.root_tag != root {
+            errors.push(ModinfoError::InvalidRoot(self.meta.root_tag.clone()));
+        }
+
+        for (tag, attributes) in &self.meta.raw_attributes {
+            if !tags.contains(&tag.as_str()) {
+                errors.push(ModinfoError::UnknownTag(tag.clone()));
+            } else if !attributes.contains_key("value") {
+                errors.push(ModinfoError::NoValueAttribute(tag.clone()));
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+
+    /// Validates the internal `name`, which 7 Days to Die uses as the mod's folder identifier
+    ///
+    /// Returns `ModinfoError::InvalidName` when the name is empty, contains a path separator
+    /// (`/` or `\`), or has leading or trailing whitespace. `display_name` is free-form and not checked.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("name", "SomeMod");
+    /// assert!(modinfo.validate_name().is_ok());
+    ///
+    /// modinfo.set_value_for("name", "Some/Mod");
+    /// assert!(modinfo.validate_name().is_err());
+    /// ```
+    pub fn validate_name(&self) -> Result<(), ModinfoError> {
+        let name = self.name().unwrap_or_default();
+
+        if name.is_empty() || name.contains(['/', '\\']) || name.trim() != name {
+            return Err(ModinfoError::InvalidName(name.to_owned()));
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the mod's folder name matches the internal `name`, since 7 Days to Die keys mods by folder
+    ///
+    /// Returns `ModinfoError::FolderNameMismatch` when they differ. The check is skipped (and passes)
+    /// when no file path is set, see `mod_folder_name`.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::path::PathBuf;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("name", "SomeMod");
+    /// assert!(modinfo.check_folder_name().is_ok());
+    ///
+    /// modinfo.set_file_path(PathBuf::from("Mods/OtherMod/ModInfo.xml"));
+    /// assert!(modinfo.check_folder_name().is_err());
+    /// ```
+    pub fn check_folder_name(&self) -> Result<(), ModinfoError> {
+        let name = self.name().unwrap_or_default();
+
+        match self.mod_folder_name() {
+            Some(folder) if folder != name => Err(ModinfoError::FolderNameMismatch {
+                folder: folder.to_owned(),
+                name: name.to_owned(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the parsed document doesn't mix V1 and V2 format markers
+    ///
+    /// A `<ModInfo>` (V1) root is inconsistent with an XML declaration, or with any V2-only element
+    /// (`DisplayName`, `Website`, `Changelog`, `Priority` or `EnableMod`) in the source. The first problem found
+    /// is returned as `ModinfoError::InconsistentFormat`. A Modinfo built in code always passes.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::str::FromStr;
+    ///
+    /// let modinfo = Modinfo::from_str(r#"<ModInfo><Website value="https://example.org" /></ModInfo>"#).unwrap();
+    ///
+    /// assert!(modinfo.format_consistency().is_err());
+    /// ```
+    pub fn format_consistency(&self) -> Result<(), ModinfoError> {
+        if self.meta.root_tag != "ModInfo" {
+            return Ok(());
+        }
+
+        if self.meta.declaration.is_some() {
+            return Err(ModinfoError::InconsistentFormat(String::from(
+                "XML declaration (V2) with a <ModInfo> root (V1)",
+            )));
+        }
+
+        match ["DisplayName", "Website", "Changelog", "Priority", "EnableMod"]
+            .iter()
+            .find(|tag| self.meta.tag_counts.contains_key(**tag))
+        {
+            Some(tag) => Err(ModinfoError::InconsistentFormat(format!(
+                "V2-only <{}> element with a <ModInfo> root (V1)",
+                tag
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Retrieve the value for a given field.
+    ///
+    /// Note: This is not case-sensitive so you can use `get_value_for("Author")` or `get_value_for("author")`
+    ///
+    /// `version` returns the modlet version as a string (without compat), use `get_version` for the
+    /// parsed `semver::Version`. The numeric `priority` and `enable_mod` are excluded from this
+    /// function, use `get_owned_value_for` instead
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("author", "Joe");
+    ///
+    /// assert_eq!(modinfo.get_value_for("author"), Some(&std::borrow::Cow::from("Joe")));
+    /// assert_eq!(modinfo.get_value_for("version"), Some(&std::borrow::Cow::from("0.1.0")));
+    /// ```
+    pub fn get_value_for<F>(&self, field: F) -> Option<&Cow<'_, str>>
+    where
+        F: AsRef<str>,
+    {
+        match field.as_ref().to_lowercase().as_ref() {
+            "author" => self.author.value.as_ref(),
+            "description" => self.description.value.as_ref(),
+            "display_name" => self.display_name.value.as_ref(),
+            "name" => self.name.value.as_ref(),
+            "website" => self.website.value.as_ref(),
+            "changelog" => self.changelog.value.as_ref(),
+            "compat" => self.version.compat.as_ref(),
+            "version" => Some(&self.version.rendered),
+            _ => None,
+        }
+    }
+
+    /// Retrieve the value for a given field as an owned string, including computed values
+    ///
+    /// Works like `get_value_for`, but also covers `priority` and `enable_mod`.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("author", "Joe");
+    ///
+    /// assert_eq!(modinfo.get_owned_value_for("author"), Some(String::from("Joe")));
+    /// assert_eq!(modinfo.get_owned_value_for("Version"), Some(String::from("0.1.0")));
+    /// ```
+    pub fn get_owned_value_for<F>(&self, field: F) -> Option<String>
+    where
+        F: AsRef<str>,
+    {
+        match field.as_ref().to_lowercase().as_ref() {
+            "priority" => self.priority.map(|priority| priority.to_string()),
+            "enable_mod" => self.enabled.map(|enabled| enabled.to_string()),
+            field => self.get_value_for(field).map(|value| value.to_string()),
+        }
+    }
+
+    /// Retrieve the name
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("name", "SomeMod");
+    ///
+    /// assert_eq!(modinfo.name(), Some("SomeMod"));
+    /// ```
+    pub fn name(&self) -> Option<&str> {
+        self.name.value.as_deref()
+    }
+
+    /// Retrieve the display name
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("display_name", "Some Mod");
+    ///
+    /// assert_eq!(modinfo.display_name(), Some("Some Mod"));
+    /// ```
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.value.as_deref()
+    }
+
+    /// Retrieve the author
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("author", "Some Author");
+    ///
+    /// assert_eq!(modinfo.author(), Some("Some Author"));
+    /// ```
+    pub fn author(&self) -> Option<&str> {
+        self.author.value.as_deref()
+    }
+
+    /// Retrieve the individual authors
+    ///
+    /// These are the entries of an `<Authors>` list, or just the flat `author` when there is no list.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::str::FromStr;
+    ///
+    /// let xml = r#"<xml><Authors><Author value="A" /><Author value="B" /></Authors></xml>"#;
+    /// let modinfo = Modinfo::from_str(xml).unwrap();
+    ///
+    /// assert_eq!(modinfo.authors(), vec!["A", "B"]);
+    /// assert_eq!(modinfo.author(), Some("A, B"));
+    /// ```
+    pub fn authors(&self) -> Vec<&str> {
+        match self.authors.is_empty() {
+            true => self.author().into_iter().collect(),
+            false => self.authors.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Sets the individual authors, and the flat `author` to them joined with `, `
+    ///
+    /// More than one author is written as an `<Authors>` list (V2 only).
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_authors(&["A", "B"]);
+    ///
+    /// assert_eq!(modinfo.author(), Some("A, B"));
+    /// assert!(modinfo.to_string().contains("<Authors>"));
+    /// ```
+    pub fn set_authors(&mut self, authors: &[&str]) {
+        self.authors = authors.iter().map(|author| author.to_string()).collect();
+        self.author.value = match authors.is_empty() {
+            true => None,
+            false => Some(authors.join(", ").into()),
+        };
+    }
+
+    /// Retrieve the description
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("description", "Some Description");
+    ///
+    /// assert_eq!(modinfo.description(), Some("Some Description"));
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.description.value.as_deref()
+    }
+
+    /// Retrieve the website
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("website", "https://example.org");
+    ///
+    /// assert_eq!(modinfo.website(), Some("https://example.org"));
+    /// ```
+    pub fn website(&self) -> Option<&str> {
+        self.website.value.as_deref()
+    }
+
+    /// Retrieve the compat (game version)
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("compat", "A21");
+    ///
+    /// assert_eq!(modinfo.compat(), Some("A21"));
+    /// ```
+    pub fn compat(&self) -> Option<&str> {
+        self.version.compat.as_deref()
+    }
+
+    /// Retrieve the best human-readable name: the display name if set, otherwise the name
+    ///
+    /// Empty values are skipped, so this returns `None` only when neither is set
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("name", "SomeMod");
+    /// assert_eq!(modinfo.best_name(), Some("SomeMod"));
+    ///
+    /// modinfo.set_value_for("display_name", "Some Mod");
+    /// assert_eq!(modinfo.best_name(), Some("Some Mod"));
+    /// ```
+    pub fn best_name(&self) -> Option<&str> {
+        self.display_name()
+            .filter(|name| !name.is_empty())
+            .or_else(|| self.name().filter(|name| !name.is_empty()))
+    }
+
+    /// Returns true if the display name was explicitly provided, rather than derived from `name`
+    ///
+    /// V1 files have no `<DisplayName>`, so their display name is always derived (title-cased from the name).
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::str::FromStr;
+    ///
+    /// let modinfo = Modinfo::from_str(r#"<ModInfo><Name value="SomeMod" /></ModInfo>"#).unwrap();
+    ///
+    /// assert_eq!(modinfo.display_name(), Some("Some Mod"));
+    /// assert!(!modinfo.display_name_is_explicit());
+    /// ```
+    pub fn display_name_is_explicit(&self) -> bool {
+        self.display_name.value.is_some() && !self.meta.display_name_derived
+    }
+
+    /// A key for sorting modinfos alphabetically, the lowercased `best_name`
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("display_name", "Some Mod");
+    ///
+    /// assert_eq!(modinfo.sort_key(), "some mod");
+    /// ```
+    pub fn sort_key(&self) -> String {
+        self.best_name().unwrap_or_default().to_lowercase()
+    }
+
+    /// A one-line, human-readable summary for logs and listings, e.g. `Some Mod v1.2.3 (A99) by Name`
+    ///
+    /// Built from `best_name`, the version, compat and author; missing pieces are left out.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("name", "SomeMod");
+    ///
+    /// assert_eq!(modinfo.summary(), "SomeMod v0.1.0");
+    /// ```
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(name) = self.best_name() {
+            parts.push(name.to_owned());
+        }
+        parts.push(format!("v{}", self.version_display_human()));
+        if let Some(author) = self.author().filter(|author| !author.is_empty()) {
+            parts.push(format!("by {}", author));
+        }
+
+        parts.join(" ")
+    }
+
+    /// Exports the Modinfo as a small JSON object, without needing serde
+    ///
+    /// The keys are `name`, `display_name`, `version`, `compat`, `description`, `author`, `website`
+    /// and `modinfo_version`; fields without a value are `null`.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("name", "SomeMod");
+    ///
+    /// assert!(modinfo.to_json().starts_with(r#"{"name":"SomeMod","display_name":null,"version":"0.1.0""#));
+    /// ```
+    pub fn to_json(&self) -> String {
+        let fields = [
+            ("name", self.name().map(Cow::from)),
+            ("display_name", self.display_name().map(Cow::from)),
+            ("version", Some(Cow::from(self.version_display_xml()))),
+            ("compat", self.compat().map(Cow::from)),
+            ("description", self.description().map(Cow::from)),
+            ("author", self.author().map(Cow::from)),
+            ("website", self.website().map(Cow::from)),
+            ("modinfo_version", Some(Cow::from(self.meta.version.to_string()))),
+        ];
+
+        let members: Vec<String> = fields
+            .iter()
+            .map(|(key, value)| match value {
+                Some(value) => format!("{}:{}", json_string(key), json_string(value)),
+                None => format!("{}:null", json_string(key)),
+            })
+            .collect();
+
+        format!("{{{}}}", members.join(","))
+    }
+
+    /// Returns the set fields as owned `(field, value)` pairs, in canonical order
+    ///
+    /// Intended for templating engines: `version` is the full semver string, and `compat`
+    /// follows it as a separate entry. Fields without a value are left out.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("name", "SomeMod");
+    /// modinfo.set_value_for("compat", "A21");
+    ///
+    /// assert_eq!(
+    ///     modinfo.to_ordered_map(),
+    ///     vec![
+    ///         ("name".to_string(), "SomeMod".to_string()),
+    ///         ("version".to_string(), "0.1.0".to_string()),
+    ///         ("compat".to_string(), "A21".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn to_ordered_map(&self) -> Vec<(String, String)> {
+        let mut map = Vec::new();
+
+        for field in FIELDS {
+            if let Some(value) = self.get_owned_value_for(field) {
+                map.push((field.to_owned(), value));
+            }
+            if field == "version" {
+                if let Some(compat) = self.compat() {
+                    map.push((String::from("compat"), compat.to_owned()));
+                }
+            }
+        }
+
+        map
+    }
+
+    /// Lists the fields whose values differ from a fresh `Modinfo::default()`, in canonical order
+    ///
+    /// Useful for telling a stub apart from a meaningfully filled in modinfo.
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoField};
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// assert!(modinfo.non_default_fields().is_empty());
+    ///
+    /// modinfo.set_value_for("author", "Me");
+    /// assert_eq!(modinfo.non_default_fields(), vec![ModinfoField::Author]);
+    /// ```
+    pub fn non_default_fields(&self) -> Vec<ModinfoField> {
+        let default = Modinfo::default();
+
+        ModinfoField::ALL
+            .into_iter()
+            .filter(|field| self.get_owned_value_for(field.as_str()) != default.get_owned_value_for(field.as_str()))
+            .collect()
+    }
+
+    /// Scores how completely the Modinfo is filled in, from 0 to 100
+    ///
+    /// The required fields `name`, `author`, `version` and `description` are worth 20 points each,
+    /// so a modinfo with just those scores 80. The optional `display_name` and `website` are worth
+    /// 7 points each, and `compat` the remaining 6. Empty values do not count, and `version` only
+    /// counts when it was present in the source (or set since), and `display_name` only when it was not
+    /// derived from `name`.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let modinfo = Modinfo::scaffold("SomeMod", "Me");
+    ///
+    /// assert_eq!(modinfo.completeness(), 80);
+    /// ```
+    pub fn completeness(&self) -> u8 {
+        let present = |value: Option<&str>| value.is_some_and(|value| !value.trim().is_empty());
+        let scores = [
+            (present(self.name()), 20),
+            (present(self.author()), 20),
+            (!self.meta.version_missing, 20),
+            (present(self.description()), 20),
+            (present(self.display_name()) && !self.meta.display_name_derived, 7),
+            (present(self.website()), 7),
+            (present(self.compat()), 6),
+        ];
+
+        scores
+            .iter()
+            .filter(|(present, _)| *present)
+            .map(|(_, score)| score)
+            .sum()
+    }
+
+    /// Set the value for a given `field` to `value`
+    ///
+    /// Note: `field` is not case-sensitive, so you can use `set_value_for("Author", "Joe")` or `get_value_for("author", "Joe")`
+    ///
+    /// A `priority` which is not a number, or an `enable_mod` which is not a boolean, is ignored;
+    /// use `try_set_value_for` to report it.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("name", "MyMod");
+    ///
+    /// assert_eq!(modinfo.get_value_for("name"), Some(&std::borrow::Cow::from("MyMod")));
+    /// ```
+    pub fn set_value_for(&mut self, field: &str, value: &str) {
+        match field.to_lowercase().as_ref() {
+            "author" => {
+                self.author.value = Some(value.to_owned().into());
+                self.authors.clear();
+            }
+            "description" => self.description.value = Some(value.to_owned().into()),
+            "display_name" => {
+                self.display_name.value = Some(value.to_owned().into());
+                self.meta.display_name_derived = false;
+            }
+            "name" => self.name.value = Some(value.to_owned().into()),
+            "website" => self.website.value = Some(value.to_owned().into()),
+            "changelog" => self.changelog.value = Some(value.to_owned().into()),
+            "version" => {
+                let (version, error) = try_parse_version(value);
+                self.version.update(|value| *value = version);
+                self.meta.version_parse_error = error;
+                self.meta.version_missing = false;
+            }
+            "compat" => self.version.compat = Some(value.to_owned().into()),
+            "priority" => {
+                if let Ok(priority) = value.trim().parse() {
+                    self.priority = Some(priority);
+                }
+            }
+            "enable_mod" => {
+                if let Ok(enabled) = parse_enabled(value) {
+                    self.enabled = Some(enabled);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Clears the value for a given `field`, so that it is absent again
+    ///
+    /// Clearing `version` resets it to the default (`0.1.0`) and clears compat, as a Modinfo
+    /// always has a version, but it counts as missing (e.g. for `validate`) until a version is set
+    /// again. Clearing `compat` only removes the compat value.
+    /// Cleared elements are written with an empty value, unless `WriteOptions::omit_empty` is set
+    /// (`changelog` is always omitted once cleared).
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("website", "https://example.org");
+    /// modinfo.clear_value_for("website");
+    ///
+    /// assert_eq!(modinfo.get_value_for("website"), None);
+    /// ```
+    pub fn clear_value_for(&mut self, field: &str) {
+        match field.to_lowercase().as_ref() {
+            "author" => {
+                self.author.value = None;
+                self.authors.clear();
+            }
+            "description" => self.description.value = None,
+            "display_name" => self.display_name.value = None,
+            "name" => self.name.value = None,
+            "website" => self.website.value = None,
+            "changelog" => self.changelog.value = None,
+            "priority" => self.priority = None,
+            "enable_mod" => self.enabled = None,
+            "version" => {
+                self.version = ModinfoValueVersion::default();
+                self.meta.version_missing = true;
+                self.meta.version_parse_error = None;
+            }
+            "compat" => self.version.compat = None,
+            _ => (),
+        }
+    }
+
+    /// Applies a sparse `ModinfoPatch`, setting only the fields which are `Some`
+    ///
+    /// Returns `ModinfoError::InvalidVersionString` if the patched version can't be parsed, in which
+    /// case `self` is left unchanged.
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoPatch};
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("author", "Me");
+    /// modinfo.apply_patch(&ModinfoPatch { website: Some("https://example.org".to_owned()), ..Default::default() }).unwrap();
+    ///
+    /// assert_eq!(modinfo.website(), Some("https://example.org"));
+    /// assert_eq!(modinfo.author(), Some("Me"));
+    /// ```
+    pub fn apply_patch(&mut self, patch: &ModinfoPatch) -> Result<(), ModinfoError> {
+        let overrides = [
+            ("author", &patch.author),
+            ("changelog", &patch.changelog),
+            ("compat", &patch.compat),
+            ("description", &patch.description),
+            ("display_name", &patch.display_name),
+            ("name", &patch.name),
+            ("version", &patch.version),
+            ("website", &patch.website),
+        ];
+
+        let mut patched = self.clone();
+        for (field, value) in overrides {
+            if let Some(value) = value {
+                patched.try_set_value_for(field, value)?;
+            }
+        }
+        *self = patched;
+
+        Ok(())
+    }
+
+    /// Renames the mod's internal `name`, optionally regenerating `display_name` from it
+    ///
+    /// No filesystem operations are performed. If the modinfo.xml path lives in a folder named after
+    /// the old name, the path it would have after renaming that folder is returned as a suggestion.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::path::PathBuf;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("name", "OldMod");
+    /// modinfo.set_file_path(PathBuf::from("Mods/OldMod/ModInfo.xml"));
+    ///
+    /// let suggested = modinfo.rename("NewMod", true);
+    ///
+    /// assert_eq!(modinfo.display_name(), Some("New Mod"));
+    /// assert_eq!(suggested, Some(PathBuf::from("Mods/NewMod/ModInfo.xml")));
+    /// ```
+    pub fn rename(&mut self, new_name: &str, update_display: bool) -> Option<PathBuf> {
+        let old_name = self.name.value.take();
+        self.set_value_for("name", new_name);
+        if update_display {
+            self.set_value_for("display_name", &new_name.to_case(Case::Title));
+            self.meta.display_name_derived = true;
+        }
+
+        let folder = self.meta.path.parent()?;
+        match (old_name, folder.file_name(), self.meta.path.file_name()) {
+            (Some(old_name), Some(folder_name), Some(file_name)) if folder_name == old_name.as_ref() => {
+                Some(folder.with_file_name(new_name).join(file_name))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns whether mod managers should enable the mod, if an `<EnableMod>` value is set (V2 only)
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::str::FromStr;
+    ///
+    /// let modinfo = Modinfo::from_str(r#"<xml><EnableMod value="false" /></xml>"#).unwrap();
+    ///
+    /// assert_eq!(modinfo.is_enabled(), Some(false));
+    /// ```
+    pub fn is_enabled(&self) -> Option<bool> {
+        self.enabled
+    }
+
+    /// Sets whether mod managers should enable the mod (V2 only)
+    ///
+    /// The `<EnableMod>` element is only written for V2 files, and only when a value is set
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_enabled(Some(true));
+    ///
+    /// assert!(modinfo.to_string().contains(r#"<EnableMod value="true"/>"#));
+    /// ```
+    pub fn set_enabled(&mut self, enabled: Option<bool>) {
+        self.enabled = enabled;
+    }
+
+    /// Retrieve the load-order priority (V2 only)
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_priority(Some(10));
+    ///
+    /// assert_eq!(modinfo.get_priority(), Some(10));
+    /// ```
+    pub fn get_priority(&self) -> Option<i32> {
+        self.priority
+    }
+
+    /// Sets the load-order priority (V2 only)
+    ///
+    /// The `<Priority>` element is only written for V2 files, and only when a priority is set
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_priority(Some(10));
+    ///
+    /// assert!(modinfo.to_string().contains(r#"<Priority value="10"/>"#));
+    /// ```
+    pub fn set_priority(&mut self, priority: Option<i32>) {
+        self.priority = priority;
+    }
+
+    /// Retrieve the display name for the given language
+    ///
+    /// Falls back to the default display name when no localized entry exists for `lang`
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("display_name", "Some Mod");
+    /// modinfo.set_display_name_localized("de", "Ein Mod");
+    ///
+    /// assert_eq!(modinfo.get_display_name_localized("de"), Some("Ein Mod"));
+    /// assert_eq!(modinfo.get_display_name_localized("fr"), Some("Some Mod"));
+    /// ```
+    pub fn get_display_name_localized(&self, lang: &str) -> Option<&str> {
+        match self.display_name_localized.get(lang) {
+            Some(value) => Some(value.as_str()),
+            None => self.display_name.value.as_deref(),
+        }
+    }
+
+    /// Sets the display name for the given language
+    ///
+    /// Localized display names are written as additional `<DisplayName value="..." lang="..." />`
+    /// elements (V2 only)
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_display_name_localized("en", "Some Mod");
+    ///
+    /// assert_eq!(modinfo.get_display_name_localized("en"), Some("Some Mod"));
+    /// ```
+    pub fn set_display_name_localized(&mut self, lang: &str, value: &str) {
+        self.display_name_localized.insert(lang.to_owned(), value.to_owned());
+    }
+
+    /// Set the value for a given `field` to `value`, reporting failures
+    ///
+    /// Unlike `set_value_for`, this returns `ModinfoError::UnknownTag` for an unrecognized `field`,
+    /// `ModinfoError::InvalidVersionString` if `version` can't be parsed (no fallback version is used), and
+    /// `ModinfoError::InvalidPriority` if `priority` is not a number, and `ModinfoError::InvalidEnableMod`
+    /// if `enable_mod` is not a boolean.
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoError};
+    ///
+    /// let mut modinfo = Modinfo::default();
+    ///
+    /// assert!(modinfo.try_set_value_for("author", "Joe").is_ok());
+    /// assert!(matches!(modinfo.try_set_value_for("nope", "Joe"), Err(ModinfoError::UnknownTag(_))));
+    /// ```
+    pub fn try_set_value_for(&mut self, field: &str, value: &str) -> Result<(), ModinfoError> {
+        match field.to_lowercase().as_ref() {
+            "author" | "changelog" | "description" | "display_name" | "name" | "website" | "compat" => {
+                self.set_value_for(field, value);
+                Ok(())
+            }
+            "priority" => {
+                let priority = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| ModinfoError::InvalidPriority(value.to_owned()))?;
+                self.priority = Some(priority);
+                Ok(())
+            }
+            "enable_mod" => {
+                self.enabled = Some(parse_enabled(value)?);
+                Ok(())
+            }
+            "version" => {
+                let options = ParseOptions {
+                    version_fallback: None,
+                    ..Default::default()
+                };
+                self.set_version_with_options(value, &options)
+            }
+            _ => Err(ModinfoError::UnknownTag(field.to_owned())),
+        }
+    }
+
+    /// Retrieve the changelog (V2 only)
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_changelog("Fixed all the things");
+    ///
+    /// assert_eq!(modinfo.get_changelog(), Some("Fixed all the things"));
+    /// ```
+    pub fn get_changelog(&self) -> Option<&str> {
+        self.changelog.value.as_deref()
+    }
+
+    /// Sets the changelog (V2 only)
+    ///
+    /// The `<Changelog>` element is only written for V2 files, and only when a changelog is set
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_changelog("Fixed all the things");
+    ///
+    /// assert!(modinfo.to_string().contains(r#"<Changelog value="Fixed all the things"/>"#));
+    /// ```
+    pub fn set_changelog(&mut self, changelog: &str) {
+        self.set_value_for("changelog", changelog)
+    }
+
+    /// Retrieve the value for the version field included the ModInfo
+    ///
+    /// Note: This is the version of the modlet, not the version of the ModInfo file format
+    /// Please see `get_modinfo_version` for that.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let modinfo = Modinfo::default();
+    ///
+    /// assert_eq!(modinfo.get_version(), &semver::Version::new(0, 1, 0));
+    /// ```
+    pub fn get_version(&self) -> &Version {
+        &self.version.value
+    }
+
+    /// Retrieve the modlet version together with the parsed compat (game version)
+    ///
+    /// The compat is `None` if it is absent or cannot be parsed as a single `CompatVersion`
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("compat", "A21");
+    ///
+    /// let (version, compat) = modinfo.get_version_with_compat();
+    /// assert_eq!(version, &semver::Version::new(0, 1, 0));
+    /// assert_eq!(compat.unwrap().to_string(), "A21");
+    /// ```
+    pub fn get_version_with_compat(&self) -> (&Version, Option<CompatVersion>) {
+        let compat = self
+            .version
+            .compat
+            .as_ref()
+            .and_then(|compat| CompatVersion::from_str(compat).ok());
+
+        (&self.version.value, compat)
+    }
+
+    /// Formats the modlet version for people, including the compat when set (e.g. `1.2.3 (A99)`)
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("compat", "A99");
+    ///
+    /// assert_eq!(modinfo.version_display_human(), "0.1.0 (A99)");
+    /// ```
+    pub fn version_display_human(&self) -> String {
+        self.version.to_string()
+    }
+
+    /// Formats the modlet version as written to the `<Version>` value attribute (e.g. `1.2.3`)
+    ///
+    /// The compat is never included, it is written as its own `compat` attribute.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("compat", "A99");
+    ///
+    /// assert_eq!(modinfo.version_display_xml(), "0.1.0");
+    /// ```
+    pub fn version_display_xml(&self) -> String {
+        self.version.value.to_string()
+    }
+
+    /// Sets the version field inside the modinfo.xml file (modlet version)
+    ///
+    /// Input is parsed leniently (`v1.2.3` and `1.2` are accepted), but output is always canonical
+    /// semver, so these are written as `1.2.3` and `1.2.0`.
+    ///
+    /// This always uses the default parsing (an unparseable version falls back to `0.0.0`), whatever
+    /// `ParseOptions` the Modinfo was parsed with; use `set_version_with_options` to apply them.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("v1.2.3".to_owned());
+    ///
+    /// assert_eq!(modinfo.get_version(), &semver::Version::new(1, 2, 3));
+    /// ```
+    pub fn set_version(&mut self, version: String) {
+        self.set_value_for("version", &version)
+    }
+
+    /// Retrieves the error from the last version which could not be parsed, if the fallback version is in use
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::str::FromStr;
+    ///
+    /// let mut modinfo = Modinfo::from_str(r#"<xml><Version value="banana" /></xml>"#).unwrap();
+    /// assert_eq!(modinfo.get_version(), &semver::Version::new(0, 0, 0));
+    /// assert!(modinfo.version_parse_error().is_some());
+    ///
+    /// modinfo.set_version("1.0.0".to_owned());
+    /// assert_eq!(modinfo.version_parse_error(), None);
+    /// ```
+    pub fn version_parse_error(&self) -> Option<&str> {
+        self.meta.version_parse_error.as_deref()
+    }
+
+    /// Sets the version field from its numeric parts, removing any pre or build data
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3-foo+bar".to_owned());
+    /// modinfo.set_version_parts(2, 5, 9);
+    ///
+    /// assert_eq!(modinfo.get_version(), &semver::Version::new(2, 5, 9));
+    /// assert!(modinfo.get_version().pre.is_empty());
+    /// assert!(modinfo.get_version().build.is_empty());
+    /// ```
+    pub fn set_version_parts(&mut self, major: u64, minor: u64, patch: u64) {
+        self.version.update(|value| *value = Version::new(major, minor, patch));
+        self.meta.version_parse_error = None;
+        self.meta.version_missing = false;
+    }
+
+    /// Sets the version field using the `strict_semver` and `version_fallback` settings of the given `ParseOptions`
+    ///
+    /// Returns `ModinfoError::InvalidVersionString` if the version can't be parsed and there is no fallback,
+    /// or if it isn't a complete semver in strict mode
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ParseOptions};
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// let options = ParseOptions { version_fallback: Some(semver::Version::new(1, 0, 0)), ..Default::default() };
+    /// modinfo.set_version_with_options("banana", &options).unwrap();
+    ///
+    /// assert_eq!(modinfo.get_version(), &semver::Version::new(1, 0, 0));
+    ///
+    /// let options = ParseOptions { version_fallback: None, ..Default::default() };
+    /// assert!(modinfo.set_version_with_options("banana", &options).is_err());
+    /// ```
+    pub fn set_version_with_options(&mut self, version: &str, options: &ParseOptions) -> Result<(), ModinfoError> {
+        let (version, error) = parse_version_with_options(version, options)?;
+        self.version.update(|value| *value = version);
+        self.meta.version_parse_error = error;
+        self.meta.version_missing = false;
+
+        Ok(())
+    }
+
+    /// Compares the modlet versions of two modinfos using semver precedence
+    ///
+    /// Pre-release versions order before their release (`1.0.0-rc.1 < 1.0.0`),
+    /// and build metadata is ignored (`1.0.0+a == 1.0.0+b`).
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut rc = Modinfo::default();
+    /// rc.set_version("1.0.0-rc.1".to_owned());
+    ///
+    /// let mut release = Modinfo::default();
+    /// release.set_version("1.0.0".to_owned());
+    ///
+    /// assert_eq!(rc.version_precedence(&release), Ordering::Less);
+    /// ```
+    pub fn version_precedence(&self, other: &Modinfo) -> std::cmp::Ordering {
+        self.version.value.cmp_precedence(&other.version.value)
+    }
+
+    /// Returns true if this modinfo is a newer release of the `installed` mod
+    ///
+    /// Both must share the same `name` (the mod's identity) and this version must have a
+    /// strictly higher semver precedence; build metadata is ignored.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut installed = Modinfo::default();
+    /// installed.set_value_for("name", "SomeMod");
+    /// installed.set_version("1.0.0".to_owned());
+    ///
+    /// let mut update = installed.clone();
+    /// update.set_version("1.1.0".to_owned());
+    ///
+    /// assert!(update.is_upgrade_over(&installed));
+    /// assert!(!installed.is_upgrade_over(&update));
+    /// ```
+    pub fn is_upgrade_over(&self, installed: &Modinfo) -> bool {
+        self.name.value.is_some()
+            && self.name == installed.name
+            && self.version_precedence(installed) == std::cmp::Ordering::Greater
+    }
+
+    /// Returns true if the modlet version matches the given semver requirement, e.g. `>=1.0, <2.0`
+    ///
+    /// Matching follows the `semver` crate, so a pre-release version only satisfies a requirement
+    /// which names a pre-release of the same `MAJOR.MINOR.PATCH`.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use semver::VersionReq;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3".to_owned());
+    ///
+    /// assert!(modinfo.version_satisfies(&VersionReq::parse(">=1.0, <2.0").unwrap()));
+    /// ```
+    pub fn version_satisfies(&self, req: &semver::VersionReq) -> bool {
+        req.matches(&self.version.value)
+    }
+
+    /// Returns true if the `<Version>` value of the parsed source was in full `MAJOR.MINOR.PATCH` form
+    ///
+    /// Versions are parsed leniently (so `1.2` is accepted), but are always stored and
+    /// written as a complete semver, which is what 7 Days to Die expects. This reports whether
+    /// the source as parsed already had that shape, i.e. whether writing it back changes the version.
+    /// A Modinfo without a parsed `<Version>` (such as one built in code) is always normalized.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::str::FromStr;
+    ///
+    /// let modinfo = Modinfo::from_str(r#"<xml><Version value="1.2" /></xml>"#).unwrap();
+    ///
+    /// assert!(!modinfo.version_is_normalized());
+    /// assert_eq!(modinfo.get_version().to_string(), "1.2.0");
+    /// ```
+    pub fn version_is_normalized(&self) -> bool {
+        match self
+            .raw_attributes("Version")
+            .and_then(|attributes| attributes.get("value"))
+        {
+            Some(raw_version) => raw_version.trim() == self.version.value.to_string(),
+            None => true,
+        }
+    }
+
+    /// Retrieves the compat value in a canonical shape, for comparison and display
+    ///
+    /// Each comma-separated entry is trimmed and uppercased, and entries which parse as a
+    /// `CompatVersion` are written in its canonical form (so `a21` becomes `A21`). Entries are
+    /// joined with commas as by `set_compat_list`. Returns `None` if there is no (non-blank) compat.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("compat", " a20, a21.2 ");
+    ///
+    /// assert_eq!(modinfo.compat_normalized(), Some("A20,A21.2".to_owned()));
+    /// ```
+    pub fn compat_normalized(&self) -> Option<String> {
+        let entries: Vec<String> = self
+            .compat()?
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match CompatVersion::from_str(entry) {
+                Ok(version) => version.to_string(),
+                Err(_) => entry.split_whitespace().collect::<String>().to_uppercase(),
+            })
+            .collect();
+
+        match entries.is_empty() {
+            true => None,
+            false => Some(entries.join(",")),
+        }
+    }
+
+    /// Retrieves the compat value as a list of game versions
+    ///
+    /// The raw compat string is split on commas and each entry is parsed as a `CompatVersion`;
+    /// entries which cannot be parsed are skipped.  Use `get_value_for("compat")` for the raw string.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("compat", "A20,A21.2");
+    ///
+    /// let compat = modinfo.get_compat_list();
+    /// assert_eq!(compat.len(), 2);
+    /// assert_eq!(compat[1].to_string(), "A21.2");
+    /// ```
+    pub fn get_compat_list(&self) -> Vec<CompatVersion> {
+        match &self.version.compat {
+            Some(compat) => compat
+                .split(',')
+                .filter_map(|entry| CompatVersion::from_str(entry).ok())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Sets the compat value from a list of game versions
+    ///
+    /// The versions are joined with commas, an empty list clears the compat value.
+    ///
+    /// ```rust
+    /// use modinfo::{CompatVersion, Modinfo};
+    /// use std::str::FromStr;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_compat_list(&[CompatVersion::from_str("A20").unwrap(), CompatVersion::from_str("A21").unwrap()]);
+    ///
+    /// assert_eq!(modinfo.get_value_for("compat"), Some(&std::borrow::Cow::from("A20,A21")));
+    /// ```
+    pub fn set_compat_list(&mut self, compat: &[CompatVersion]) {
+        self.version.compat = match compat.is_empty() {
+            true => None,
+            false => Some(
+                compat
+                    .iter()
+                    .map(|version| version.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+                    .into(),
+            ),
+        };
+    }
+
+    /// Sets the compat value to an Alpha game version, e.g. `21` becomes `A21`
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_compat_alpha(21);
+    ///
+    /// assert_eq!(modinfo.compat(), Some("A21"));
+    /// ```
+    pub fn set_compat_alpha(&mut self, alpha: u32) {
+        self.set_compat_list(&[CompatVersion {
+            channel: CompatChannel::Alpha,
+            major: alpha,
+            minor: None,
+        }]);
+    }
+
+    /// Sets the compat value to a stable (1.0 and later) game version, e.g. `V1` or `V1.2`
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_compat_stable(1, Some(2));
+    ///
+    /// assert_eq!(modinfo.compat(), Some("V1.2"));
+    /// ```
+    pub fn set_compat_stable(&mut self, major: u32, minor: Option<u32>) {
+        self.set_compat_list(&[CompatVersion {
+            channel: CompatChannel::Stable,
+            major,
+            minor,
+        }]);
+    }
+
+    /// Checks whether the mod declares support for the installed `game` version
+    ///
+    /// A compat entry supports `game` when it matches exactly, or when it is on the same channel
+    /// with a major version no greater than the game's (e.g. `A20` supports `A21.2`, but not `V1`).
+    /// With a compat list, any supporting entry is enough. A mod without compat is assumed compatible,
+    /// while a compat value that cannot be parsed is not.
+    ///
+    /// ```rust
+    /// use modinfo::{CompatVersion, Modinfo};
+    /// use std::str::FromStr;
+    ///
+    /// let game = CompatVersion::from_str("A21.2").unwrap();
+    /// let mut modinfo = Modinfo::default();
+    /// assert!(modinfo.is_compatible_with(&game));
+    ///
+    /// modinfo.set_value_for("compat", "A21");
+    /// assert!(modinfo.is_compatible_with(&game));
+    ///
+    /// modinfo.set_value_for("compat", "V1");
+    /// assert!(!modinfo.is_compatible_with(&game));
+    /// ```
+    pub fn is_compatible_with(&self, game: &CompatVersion) -> bool {
+        if self.version.compat.is_none() {
+            return true;
+        }
+
+        self.get_compat_list()
+            .iter()
+            .any(|compat| compat == game || (compat.channel == game.channel && compat.major <= game.major))
+    }
+
+    /// Retrieves the current version of the ModInfo.xml file (V1 or V2)
+    ///
+    /// returns a `ModinfoVersion` enum:
+    ///     ModinfoVersion::V1
+    ///     ModinfoVersion::V2
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoVersion};
+    ///
+    /// let mut modinfo = Modinfo::default();
+    ///
+    /// assert_eq!(modinfo.get_modinfo_version(), ModinfoVersion::V2);
+    /// ```
+    pub fn get_modinfo_version(&self) -> ModinfoVersion {
+        self.meta.version.clone()
+    }
+
+    /// Sets the version of the ModInfo.xml file itesle (V1 or V2)
+    ///
+    /// Accepts a `ModinfoVersion` enum:
+    ///     ModinfoVersion::V1
+    ///     ModinfoVersion::V2
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoVersion};
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_modinfo_version(ModinfoVersion::V1);
+    ///
+    /// assert_eq!(modinfo.get_modinfo_version(), ModinfoVersion::V1);
+    /// ```
+    pub fn set_modinfo_version(&mut self, version: ModinfoVersion) {
+        self.meta.version = version
+    }
+
+    /// Returns true if this is a V1 ModInfo.xml
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoVersion};
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_modinfo_version(ModinfoVersion::V1);
+    ///
+    /// assert!(modinfo.is_v1());
+    /// ```
+    pub fn is_v1(&self) -> bool {
+        self.meta.version == ModinfoVersion::V1
+    }
+
+    /// Returns true if this is a V2 ModInfo.xml
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let modinfo = Modinfo::default();
+    ///
+    /// assert!(modinfo.is_v2());
+    /// ```
+    pub fn is_v2(&self) -> bool {
+        self.meta.version == ModinfoVersion::V2
+    }
+
+    /// Upgrades the Modinfo in place to the latest (V2) format
+    ///
+    /// The root element becomes `<xml>` and an XML declaration is written on serialize, a missing
+    /// `display_name` is derived from `name` (title-cased), and the version is written in full
+    /// `MAJOR.MINOR.PATCH` form (so a V1 `1.2` becomes `1.2.0`).
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::str::FromStr;
+    ///
+    /// let mut modinfo = Modinfo::from_str(r#"<ModInfo><Name value="OldMod" /><Version value="1.2" /></ModInfo>"#).unwrap();
+    /// modinfo.upgrade();
+    ///
+    /// assert!(modinfo.is_v2());
+    /// assert!(modinfo.to_string().contains(r#"<Version value="1.2.0"/>"#));
+    /// ```
+    pub fn upgrade(&mut self) {
+        self.meta.version = ModinfoVersion::V2;
+        if !self.meta.root_tag.is_empty() {
+            self.meta.root_tag = String::from("xml");
+        }
+
+        if self.display_name.value.is_none() {
+            self.display_name.value = self.name.value.as_ref().map(|name| name.to_case(Case::Title).into());
+            self.meta.display_name_derived = true;
+        }
+    }
+
+    /// Converts the Modinfo to the V1 format, reporting any data which V1 cannot represent
+    ///
+    /// V1 has no `Website`, `DisplayName`, `Changelog`, `Priority` or `EnableMod` elements, so these are dropped (the display name
+    /// falls back to one derived from `name`, as when parsing a V1 file).  A human-readable
+    /// warning is returned for each dropped value.
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, ModinfoVersion};
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_value_for("website", "https://example.org");
+    ///
+    /// let (v1, warnings) = modinfo.to_v1_lossy();
+    ///
+    /// assert_eq!(v1.get_modinfo_version(), ModinfoVersion::V1);
+    /// assert_eq!(warnings, vec!["website 'https://example.org' dropped".to_owned()]);
+    /// ```
+    pub fn to_v1_lossy(&self) -> (Modinfo, Vec<String>) {
+        let mut modinfo = self.clone();
+        let mut warnings = Vec::new();
+        let derived_display_name = self.name.value.as_ref().map(|name| name.to_case(Case::Title).into());
+
+        if let Some(website) = modinfo.website.value.take() {
+            warnings.push(format!("website '{}' dropped", website));
+        }
+
+        if let Some(changelog) = modinfo.changelog.value.take() {
+            warnings.push(format!("changelog '{}' dropped", changelog));
+        }
+
+        if let Some(priority) = modinfo.priority.take() {
+            warnings.push(format!("priority '{}' dropped", priority));
+        }
+
+        if let Some(enabled) = modinfo.enabled.take() {
+            warnings.push(format!("enable_mod '{}' dropped", enabled));
+        }
+
+        if let Some(display_name) = &self.display_name.value {
+            if self.display_name.value != derived_display_name {
+                warnings.push(format!("display_name '{}' dropped", display_name));
             }
         }
+        modinfo.display_name.value = derived_display_name;
+        modinfo.meta.display_name_derived = true;
 
-        Ok(())
+        for (lang, display_name) in std::mem::take(&mut modinfo.display_name_localized) {
+            warnings.push(format!("display_name '{}' ({}) dropped", display_name, lang));
+        }
+
+        modinfo.meta.version = ModinfoVersion::V1;
+
+        (modinfo, warnings)
     }
 
-    /// Retrieve the value for a given field.
+    /// Builds the path the mod is expected to be installed at: `mods_root/<name>/ModInfo.xml`
     ///
-    /// Note: This is not case-sensitive so you can use `get_value_for("Author")` or `get_value_for("author")`
+    /// The internal `name` (not the display name) is used as the folder name. Returns `None` if the
+    /// name is not usable as a folder name, see `validate_name`.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use std::path::PathBuf;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// assert_eq!(modinfo.install_path("Mods"), None);
     ///
-    /// Please note that `version` is excluded from this function, use `get_version` instead
+    /// modinfo.set_value_for("name", "SomeMod");
+    /// assert_eq!(modinfo.install_path("Mods"), Some(PathBuf::from("Mods/SomeMod/ModInfo.xml")));
+    /// ```
+    pub fn install_path(&self, mods_root: impl AsRef<Path>) -> Option<PathBuf> {
+        self.validate_name().ok()?;
+
+        Some(mods_root.as_ref().join(self.name()?).join("ModInfo.xml"))
+    }
+
+    /// Retrieves the current modinfo.xml file path
     ///
     /// ```rust
     /// use modinfo::Modinfo;
+    /// use std::path::PathBuf;
     ///
     /// let mut modinfo = Modinfo::default();
-    /// modinfo.set_value_for("author", "Joe");
+    /// modinfo.set_file_path(PathBuf::from("modinfo.xml"));
     ///
-    /// assert_eq!(modinfo.get_value_for("author"), Some(&std::borrow::Cow::from("Joe")));
+    /// assert_eq!(modinfo.get_file_path(), &PathBuf::from("modinfo.xml"));
     /// ```
-    pub fn get_value_for<F>(&self, field: F) -> Option<&Cow<'_, str>>
-    where
-        F: AsRef<str>,
-    {
-        match field.as_ref().to_lowercase().as_ref() {
-            "author" => self.author.value.as_ref(),
-            "description" => self.description.value.as_ref(),
-            "display_name" => self.display_name.value.as_ref(),
-            "name" => self.name.value.as_ref(),
-            "website" => self.website.value.as_ref(),
-            "compat" => self.version.compat.as_ref(),
-            _ => None,
-        }
+    pub fn get_file_path(&self) -> &PathBuf {
+        &self.meta.path
     }
 
-    /// Set the value for a given `field` to `value`
+    /// Sets the modinfo.xml file path
     ///
-    /// Note: `field` is not case-sensitive, so you can use `set_value_for("Author", "Joe")` or `get_value_for("author", "Joe")`
+    /// This is normally set automatically when a file is parsed, but can be set manually
+    /// such as when creating a new modinfo.xml file.
     ///
     /// ```rust
     /// use modinfo::Modinfo;
+    /// use std::path::PathBuf;
     ///
     /// let mut modinfo = Modinfo::default();
-    /// modinfo.set_value_for("name", "MyMod");
+    /// modinfo.set_file_path(PathBuf::from("modinfo.xml"));
     ///
-    /// assert_eq!(modinfo.get_value_for("name"), Some(&std::borrow::Cow::from("MyMod")));
+    /// assert_eq!(modinfo.get_file_path(), &PathBuf::from("modinfo.xml"));
     /// ```
-    pub fn set_value_for(&mut self, field: &str, value: &str) {
-        match field.to_lowercase().as_ref() {
-            "author" => self.author.value = Some(value.to_owned().into()),
-            "description" => self.description.value = Some(value.to_owned().into()),
-            "display_name" => self.display_name.value = Some(value.to_owned().into()),
-            "name" => self.name.value = Some(value.to_owned().into()),
-            "website" => self.website.value = Some(value.to_owned().into()),
-            "version" => self.version.value.set_version(value),
-            "compat" => self.version.compat = Some(value.to_owned().into()),
-            _ => (),
-        }
+    pub fn set_file_path(&mut self, path: PathBuf) {
+        self.meta.path = path.clone();
     }
 
-    /// Retrieve the value for the version field included the ModInfo
+    /// Retrieves the directory containing the modinfo.xml file, i.e. the mod's folder
     ///
-    /// Note: This is the version of the modlet, not the version of the ModInfo file format
-    /// Please see `get_modinfo_version` for that.
+    /// Returns `None` if no file path is set, or the path has no directory component.
     ///
     /// ```rust
     /// use modinfo::Modinfo;
+    /// use std::path::{Path, PathBuf};
     ///
-    /// let modinfo = Modinfo::default();
+    /// let mut modinfo = Modinfo::default();
+    /// assert_eq!(modinfo.mod_dir(), None);
     ///
-    /// assert_eq!(modinfo.get_version(), &semver::Version::new(0, 1, 0));
+    /// modinfo.set_file_path(PathBuf::from("Mods/SomeMod/ModInfo.xml"));
+    /// assert_eq!(modinfo.mod_dir(), Some(Path::new("Mods/SomeMod")));
     /// ```
-    pub fn get_version(&self) -> &Version {
-        &self.version.value
+    pub fn mod_dir(&self) -> Option<&Path> {
+        self.meta.path.parent().filter(|dir| !dir.as_os_str().is_empty())
     }
 
-    /// Sets the version field inside the modinfo.xml file (modlet version)
+    /// Retrieves the name of the mod's folder, see `mod_dir`
+    ///
+    /// 7 Days to Die identifies mods by this folder name, which may differ from the internal `name`.
     ///
     /// ```rust
     /// use modinfo::Modinfo;
+    /// use std::path::PathBuf;
     ///
     /// let mut modinfo = Modinfo::default();
-    /// modinfo.set_version("1.2.3".to_owned());
+    /// modinfo.set_file_path(PathBuf::from("Mods/SomeMod/ModInfo.xml"));
     ///
-    /// assert_eq!(modinfo.get_version(), &semver::Version::new(1, 2, 3));
+    /// assert_eq!(modinfo.mod_folder_name(), Some("SomeMod"));
     /// ```
-    pub fn set_version(&mut self, version: String) {
-        self.set_value_for("version", &version)
+    pub fn mod_folder_name(&self) -> Option<&str> {
+        self.mod_dir()?.file_name()?.to_str()
     }
 
-    /// Retrieves the current version of the ModInfo.xml file (V1 or V2)
+    /// Retrieves every attribute parsed from the given element (by tag name, e.g. `Version`)
     ///
-    /// returns a `ModinfoVersion` enum:
-    ///     ModinfoVersion::V1
-    ///     ModinfoVersion::V2
+    /// Attribute names are lowercased and always iterate in sorted order.
+    /// If the element appeared more than once, the attributes of the last occurrence are returned.
+    /// Localized entries (with a `lang` attribute) are not included, see `get_display_name_localized`.
     ///
     /// ```rust
-    /// use modinfo::{Modinfo, ModinfoVersion};
+    /// use modinfo::Modinfo;
+    /// use std::str::FromStr;
     ///
-    /// let mut modinfo = Modinfo::default();
+    /// let modinfo = Modinfo::from_str(r#"<xml><Version value="1.2.3" foo="bar" /></xml>"#).unwrap();
+    /// let attributes = modinfo.raw_attributes("Version").unwrap();
     ///
-    /// assert_eq!(modinfo.get_modinfo_version(), ModinfoVersion::V2);
+    /// assert_eq!(attributes.get("foo"), Some(&"bar".to_owned()));
+    /// assert_eq!(modinfo.raw_attributes("Website"), None);
     /// ```
-    pub fn get_modinfo_version(&self) -> ModinfoVersion {
-        self.meta.version.clone()
+    pub fn raw_attributes(&self, tag: &str) -> Option<&BTreeMap<String, String>> {
+        self.meta.raw_attributes.get(tag)
     }
 
-    /// Sets the version of the ModInfo.xml file itesle (V1 or V2)
+    /// Counts how often each element (by tag name) appeared in the parsed source
     ///
-    /// Accepts a `ModinfoVersion` enum:
-    ///     ModinfoVersion::V1
-    ///     ModinfoVersion::V2
+    /// The format is flat, so any count above one means a repeated element whose last value won.
+    /// Localized `DisplayName` entries (with a `lang` attribute) are expected to repeat and are not counted.
     ///
     /// ```rust
-    /// use modinfo::{Modinfo, ModinfoVersion};
+    /// use modinfo::Modinfo;
+    /// use std::str::FromStr;
     ///
-    /// let mut modinfo = Modinfo::default();
-    /// modinfo.set_modinfo_version(ModinfoVersion::V1);
+    /// let modinfo = Modinfo::from_str(r#"<xml><Author value="Me" /><Author value="You" /></xml>"#).unwrap();
     ///
-    /// assert_eq!(modinfo.get_modinfo_version(), ModinfoVersion::V1);
+    /// assert_eq!(modinfo.tag_counts()["Author"], 2);
     /// ```
-    pub fn set_modinfo_version(&mut self, version: ModinfoVersion) {
-        self.meta.version = version
+    pub fn tag_counts(&self) -> HashMap<String, usize> {
+        self.meta.tag_counts.clone().into_iter().collect()
     }
 
-    /// Retrieves the current modinfo.xml file path
+    /// Computes a fingerprint of the modinfo's content
+    ///
+    /// The fingerprint covers every field value (including each `<Authors>` entry), the modlet
+    /// version (including compat), and the ModInfo.xml format version, but not the file path.
+    /// It uses a deterministic hasher, so the value is reproducible across runs
+    /// and can be used as a cache key.
     ///
     /// ```rust
     /// use modinfo::Modinfo;
     /// use std::path::PathBuf;
     ///
+    /// let mut a = Modinfo::new();
+    /// a.set_value_for("name", "SomeMod");
+    ///
+    /// let mut b = a.clone();
+    /// b.set_file_path(PathBuf::from("Mods/SomeMod/ModInfo.xml"));
+    ///
+    /// assert_eq!(a.fingerprint(), b.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = StableHasher::default();
+
+        self.author.hash(&mut hasher);
+        // hashed with its length, so entries can't shift between neighbouring fields
+        self.authors.hash(&mut hasher);
+        self.changelog.hash(&mut hasher);
+        self.description.hash(&mut hasher);
+        self.display_name.hash(&mut hasher);
+        self.display_name_localized.hash(&mut hasher);
+        self.enabled.hash(&mut hasher);
+        self.name.hash(&mut hasher);
+        self.priority.hash(&mut hasher);
+        self.version.hash(&mut hasher);
+        self.website.hash(&mut hasher);
+        self.meta.version.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    /// Returns true if both modinfos describe the same release
+    ///
+    /// All content fields are compared, but only the major, minor and patch of the version, so
+    /// pre-release and build data (e.g. a `stamp_build_hash`) are ignored, as is the file path.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut a = Modinfo::default();
+    /// a.set_version("1.2.3+build.1".to_owned());
+    /// let mut b = a.clone();
+    /// b.set_version("1.2.3+build.2".to_owned());
+    ///
+    /// assert!(a.same_release(&b));
+    /// ```
+    pub fn same_release(&self, other: &Modinfo) -> bool {
+        self.release_version() == other.release_version()
+            && self.version.compat == other.version.compat
+            && self.author == other.author
+            && self.authors == other.authors
+            && self.changelog == other.changelog
+            && self.description == other.description
+            && self.display_name == other.display_name
+            && self.display_name_localized == other.display_name_localized
+            && self.enabled == other.enabled
+            && self.name == other.name
+            && self.priority == other.priority
+            && self.website == other.website
+            && self.meta.version == other.meta.version
+    }
+
+    /// Stamps a short content hash into the version's build metadata (e.g. `1.2.3+h3f2a1b09`)
+    ///
+    /// The hash is the `fingerprint` computed without any build metadata, so stamping is
+    /// idempotent: calling it again on unchanged content produces the same stamp.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
     /// let mut modinfo = Modinfo::default();
-    /// modinfo.set_file_path(PathBuf::from("modinfo.xml"));
+    /// modinfo.set_value_for("name", "SomeMod");
+    /// modinfo.stamp_build_hash();
     ///
-    /// assert_eq!(modinfo.get_file_path(), &PathBuf::from("modinfo.xml"));
+    /// let stamped = modinfo.get_version().clone();
+    /// modinfo.stamp_build_hash();
+    ///
+    /// assert_eq!(modinfo.get_version(), &stamped);
+    /// assert!(modinfo.get_version_build().unwrap().starts_with('h'));
     /// ```
-    pub fn get_file_path(&self) -> &PathBuf {
-        &self.meta.path
+    pub fn stamp_build_hash(&mut self) {
+        self.clear_version_build();
+        let hash = format!("h{:08x}", self.fingerprint() >> 32);
+
+        self.version
+            .update(|value| value.build = BuildMetadata::new(&hash).unwrap_or(BuildMetadata::EMPTY));
     }
 
-    /// Sets the modinfo.xml file path
+    /// Retrieves the Major number of the modlet version
     ///
-    /// This is normally set automatically when a file is parsed, but can be set manually
-    /// such as when creating a new modinfo.xml file.
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3".to_owned());
+    ///
+    /// assert_eq!(modinfo.version_major(), 1);
+    /// ```
+    pub fn version_major(&self) -> u64 {
+        self.version.value.major
+    }
+
+    /// Retrieves the Minor number of the modlet version
     ///
     /// ```rust
     /// use modinfo::Modinfo;
-    /// use std::path::PathBuf;
     ///
     /// let mut modinfo = Modinfo::default();
-    /// modinfo.set_file_path(PathBuf::from("modinfo.xml"));
+    /// modinfo.set_version("1.2.3".to_owned());
     ///
-    /// assert_eq!(modinfo.get_file_path(), &PathBuf::from("modinfo.xml"));
+    /// assert_eq!(modinfo.version_minor(), 2);
     /// ```
-    pub fn set_file_path(&mut self, path: PathBuf) {
-        self.meta.path = path.clone();
+    pub fn version_minor(&self) -> u64 {
+        self.version.value.minor
+    }
+
+    /// Retrieves the Patch number of the modlet version
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3".to_owned());
+    ///
+    /// assert_eq!(modinfo.version_patch(), 3);
+    /// ```
+    pub fn version_patch(&self) -> u64 {
+        self.version.value.patch
+    }
+
+    /// Bumps the version using the given `BumpStrategy`
+    ///
+    /// ```rust
+    /// use modinfo::{CalVer, Modinfo};
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.bump_with(CalVer { year: 2024, month: 3 });
+    /// modinfo.bump_with(CalVer { year: 2024, month: 3 });
+    ///
+    /// assert_eq!(modinfo.get_version(), &semver::Version::new(2024, 3, 1));
+    /// ```
+    pub fn bump_with<S: BumpStrategy>(&mut self, strategy: S) {
+        self.version.update(|value| strategy.bump(value));
     }
 
     /// Increases the Major version number by 1,
@@ -186,7 +2088,7 @@ impl Modinfo {
     /// assert_eq!(modinfo.get_version(), &semver::Version::new(2, 0, 0));
     /// ```
     pub fn bump_version_major(&mut self) {
-        self.version.value.bump_major()
+        self.version.update(|value| value.bump_major())
     }
 
     /// Increases the Minor version number by 1,
@@ -202,7 +2104,7 @@ impl Modinfo {
     /// assert_eq!(modinfo.get_version(), &semver::Version::new(1, 3, 0));
     /// ```
     pub fn bump_version_minor(&mut self) {
-        self.version.value.bump_minor()
+        self.version.update(|value| value.bump_minor())
     }
 
     /// Increases the Patch version number by 1,
@@ -217,7 +2119,24 @@ impl Modinfo {
     /// assert_eq!(modinfo.get_version(), &semver::Version::new(1, 2, 4));
     /// ```
     pub fn bump_version_patch(&mut self) {
-        self.version.value.bump_patch()
+        self.version.update(|value| value.bump_patch())
+    }
+
+    /// Increments the pre-release number, e.g. for release candidates, and removes any build data
+    ///
+    /// `1.2.3-rc.1` becomes `1.2.3-rc.2`, `1.2.3-1` becomes `1.2.3-2`, `1.2.3-beta` becomes
+    /// `1.2.3-beta.1`, and a version without a pre-release such as `1.2.3` becomes `1.2.3-rc.1`.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3-rc.1".to_owned());
+    /// modinfo.bump_pre();
+    /// assert_eq!(modinfo.get_version(), &semver::Version::parse("1.2.3-rc.2").unwrap());
+    /// ```
+    pub fn bump_pre(&mut self) {
+        self.version.update(bump_pre)
     }
 
     /// Adds a pre-release version to the version field
@@ -231,7 +2150,7 @@ impl Modinfo {
     /// assert_eq!(modinfo.get_version(), &semver::Version::parse("1.2.3-foo").unwrap());
     /// ```
     pub fn add_version_pre(&mut self, pre: &str) {
-        self.version.value.add_pre(pre)
+        self.version.update(|value| value.add_pre(pre))
     }
 
     /// Adds build data to the version field
@@ -245,6 +2164,131 @@ impl Modinfo {
     /// assert_eq!(modinfo.get_version(), &semver::Version::parse("1.2.3+bar").unwrap());
     /// ```
     pub fn add_version_build(&mut self, build: &str) {
-        self.version.value.add_build(build)
+        self.version.update(|value| value.add_build(build))
+    }
+
+    /// Retrieves the pre-release identifier of the version field, if any
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3-rc.1".to_owned());
+    ///
+    /// assert_eq!(modinfo.get_version_pre(), Some("rc.1"));
+    /// ```
+    pub fn get_version_pre(&self) -> Option<&str> {
+        match self.version.value.pre.is_empty() {
+            true => None,
+            false => Some(self.version.value.pre.as_str()),
+        }
+    }
+
+    /// Retrieves the build data of the version field, if any
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3+20240101".to_owned());
+    ///
+    /// assert_eq!(modinfo.get_version_build(), Some("20240101"));
+    /// ```
+    pub fn get_version_build(&self) -> Option<&str> {
+        match self.version.value.build.is_empty() {
+            true => None,
+            false => Some(self.version.value.build.as_str()),
+        }
+    }
+
+    /// Removes any pre-release identifier from the version field
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3-rc.1".to_owned());
+    /// modinfo.clear_version_pre();
+    ///
+    /// assert_eq!(modinfo.get_version_pre(), None);
+    /// ```
+    pub fn clear_version_pre(&mut self) {
+        self.version.update(|value| value.pre = Prerelease::EMPTY);
+    }
+
+    /// Removes any build data from the version field
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3+20240101".to_owned());
+    /// modinfo.clear_version_build();
+    ///
+    /// assert_eq!(modinfo.get_version_build(), None);
+    /// ```
+    pub fn clear_version_build(&mut self) {
+        self.version.update(|value| value.build = BuildMetadata::EMPTY);
+    }
+
+    /// Strips pre-release and build data from the version, e.g. `1.2.3-rc.1+abc` becomes `1.2.3`
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3-rc.1+abc".to_owned());
+    /// modinfo.to_release_version();
+    ///
+    /// assert_eq!(modinfo.get_version(), &semver::Version::new(1, 2, 3));
+    /// ```
+    pub fn to_release_version(&mut self) {
+        self.clear_version_pre();
+        self.clear_version_build();
+    }
+
+    /// Retrieves a copy of the version without pre-release and build data, see `to_release_version`
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// let mut modinfo = Modinfo::default();
+    /// modinfo.set_version("1.2.3-rc.1+abc".to_owned());
+    ///
+    /// assert_eq!(modinfo.release_version(), semver::Version::new(1, 2, 3));
+    /// assert_eq!(modinfo.get_version().to_string(), "1.2.3-rc.1+abc");
+    /// ```
+    pub fn release_version(&self) -> Version {
+        Version::new(
+            self.version.value.major,
+            self.version.value.minor,
+            self.version.value.patch,
+        )
     }
 }
+
+/// Removes the whitespace around each line, leaving whitespace inside values untouched
+fn strip_insignificant_ws(xml: &str) -> String {
+    xml.lines().map(str::trim).collect()
+}
+
+/// Quotes and escapes `value` as a JSON string
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+
+    json
+}
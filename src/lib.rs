@@ -3,11 +3,13 @@ use quick_xml::{events::*, reader::Reader, writer::Writer};
 use semver::{BuildMetadata, Prerelease, Version};
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt, fs,
-    io::Cursor,
+    hash::{Hash, Hasher},
+    io::{BufRead, Cursor, Write},
     path::{Path, PathBuf},
     str::FromStr,
+    time::Instant,
 };
 use thiserror::Error;
 
@@ -16,9 +18,31 @@ use thiserror::Error;
 mod tests;
 
 // Include Modules
+mod builder;
+pub use builder::*;
+
+mod compat;
+pub use compat::*;
+
+mod field;
+pub use field::*;
+
+mod fingerprint;
+use fingerprint::StableHasher;
+
 mod impls;
+#[allow(unused_imports)]
 pub use impls::*;
 
+mod options;
+pub use options::*;
+
+mod patch;
+pub use patch::*;
+
+mod stats;
+pub use stats::*;
+
 mod version_tools;
 pub use version_tools::*;
 
@@ -29,8 +53,24 @@ pub enum ModinfoError {
     IoError(std::io::Error),
     #[error("Invalid version: {0}")]
     InvalidVersion(lenient_semver_parser::Error<'static>),
+    #[error("Invalid version: {0}")]
+    InvalidVersionString(String),
+    #[error("Invalid compat version: {0}")]
+    InvalidCompat(String),
+    #[error("Invalid mod name: {0:?}")]
+    InvalidName(String),
+    #[error("Mod folder {folder:?} does not match the internal name {name:?}")]
+    FolderNameMismatch { folder: String, name: String },
+    #[error("Invalid priority: {0}")]
+    InvalidPriority(String),
+    #[error("Invalid EnableMod value, expected true or false: {0}")]
+    InvalidEnableMod(String),
     #[error("File not found")]
     FsNotFound,
+    #[error("Directory not found: {0}")]
+    FsDirNotFound(PathBuf),
+    #[error("Expected a file but found a directory: {0}")]
+    FsIsDirectory(PathBuf),
     #[error("No modinfo.xml found")]
     NoModinfo,
     #[error("No Author found in modinfo.xml")]
@@ -41,14 +81,28 @@ pub enum ModinfoError {
     NoModinfoName,
     #[error("No Version found in modinfo.xml")]
     NoModinfoVersion,
+    #[error("No {0} found in modinfo.xml")]
+    NoModinfoField(ModinfoField),
     #[error("Unable to determine the version for modinfo.xml")]
     NoModinfoValueVersion,
     #[error("Unknown tag: {0}")]
     UnknownTag(String),
+    #[error("No value attribute found for tag: {0}")]
+    NoValueAttribute(String),
+    #[error("Input too large: {size} bytes exceeds the limit of {limit} bytes")]
+    TooLarge { size: usize, limit: usize },
+    #[error("Invalid root element: {0}")]
+    InvalidRoot(String),
+    #[error("Inconsistent ModInfo.xml format: {0}")]
+    InconsistentFormat(String),
+    #[error("Unknown modinfo version: {0}")]
+    UnknownModinfoVersion(String),
     #[error("Could not write modinfo.xml")]
     WriteError,
     #[error("Could not parse XML: {0}")]
     XMLError(quick_xml::Error),
+    #[error("Could not parse XML at position {position}: {source}")]
+    XMLErrorAt { position: usize, source: quick_xml::Error },
 }
 
 impl From<std::io::Error> for ModinfoError {
@@ -100,17 +154,146 @@ pub enum ModinfoVersion {
     V2,
 }
 
-#[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+impl ModinfoVersion {
+    /// The most recent ModInfo.xml format, used by default for new modinfos
+    ///
+    /// ```rust
+    /// use modinfo::ModinfoVersion;
+    ///
+    /// assert_eq!(ModinfoVersion::latest(), ModinfoVersion::V2);
+    /// ```
+    pub const fn latest() -> Self {
+        ModinfoVersion::V2
+    }
+}
+
+impl fmt::Display for ModinfoVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModinfoVersion::V1 => write!(f, "V1"),
+            ModinfoVersion::V2 => write!(f, "V2"),
+        }
+    }
+}
+
+impl FromStr for ModinfoVersion {
+    type Err = ModinfoError;
+
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        match version.trim().to_uppercase().as_ref() {
+            "V1" => Ok(ModinfoVersion::V1),
+            "V2" => Ok(ModinfoVersion::V2),
+            _ => Err(ModinfoError::UnknownModinfoVersion(version.to_owned())),
+        }
+    }
+}
+
+/// The format version and file path of a Modinfo, plus what was observed about its source while parsing
+///
+/// Only `version` and `path` take part in equality, hashing and ordering; the rest describes how
+/// the source was written (quotes, line endings, element order, ...) so that documents
+/// with the same content compare equal however they were formatted.
+#[derive(Debug, Clone)]
 struct ModinfoValueMeta {
     version: ModinfoVersion,
     path: PathBuf,
+    element_order: Vec<String>,
+    raw_attributes: BTreeMap<String, BTreeMap<String, String>>,
+    root_tag: String,
+    root_attributes: Vec<(String, String)>,
+    declaration: Option<XmlDeclaration>,
+    prolog: Vec<PrologNode>,
+    epilogue: Vec<String>,
+    tag_counts: BTreeMap<String, usize>,
+    cdata_description: bool,
+    version_missing: bool,
+    display_name_derived: bool,
+    quote_style: QuoteStyle,
+    line_ending: LineEnding,
+    version_parse_error: Option<String>,
+}
+
+impl ModinfoValueMeta {
+    /// The fields which take part in equality, hashing and ordering
+    fn key(&self) -> (&ModinfoVersion, &PathBuf) {
+        (&self.version, &self.path)
+    }
+}
+
+impl PartialEq for ModinfoValueMeta {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for ModinfoValueMeta {}
+
+impl Hash for ModinfoValueMeta {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl PartialOrd for ModinfoValueMeta {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ModinfoValueMeta {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+/// Markup found between the XML declaration and the root element, re-emitted as-is
+#[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum PrologNode {
+    /// e.g. `<?xml-stylesheet href="modinfo.xsl"?>`
+    ProcessingInstruction(String),
+    /// e.g. `<!DOCTYPE xml>`
+    DocType(String),
+}
+
+/// The `<?xml ... ?>` declaration of a parsed file, kept so it can be written back unchanged
+///
+/// The encoding is not kept: output is always UTF-8, so it is always declared as such.
+#[derive(Debug, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct XmlDeclaration {
+    version: String,
+    standalone: Option<String>,
+}
+
+impl XmlDeclaration {
+    fn from_decl(decl: &BytesDecl) -> Result<Self, ModinfoError> {
+        let text = |bytes: Cow<[u8]>| String::from_utf8_lossy(&bytes).into_owned();
+
+        Ok(XmlDeclaration {
+            version: text(decl.version()?),
+            standalone: decl.standalone().transpose()?.map(text),
+        })
+    }
 }
 
 impl Default for ModinfoValueMeta {
     fn default() -> Self {
         ModinfoValueMeta {
-            version: ModinfoVersion::V2,
+            version: ModinfoVersion::latest(),
             path: PathBuf::new(),
+            element_order: Vec::new(),
+            raw_attributes: BTreeMap::new(),
+            root_tag: String::new(),
+            root_attributes: Vec::new(),
+            declaration: None,
+            prolog: Vec::new(),
+            epilogue: Vec::new(),
+            tag_counts: BTreeMap::new(),
+            cdata_description: false,
+            version_missing: false,
+            display_name_derived: false,
+            quote_style: QuoteStyle::Double,
+            line_ending: LineEnding::Lf,
+            version_parse_error: None,
         }
     }
 }
@@ -133,6 +316,30 @@ impl fmt::Display for ModinfoValue {
 struct ModinfoValueVersion {
     value: Version,
     compat: Option<Cow<'static, str>>,
+    /// `value` as a string, kept up to date so that `get_value_for("version")` can return a reference
+    rendered: Cow<'static, str>,
+}
+
+impl ModinfoValueVersion {
+    fn new(value: Version, compat: Option<Cow<'static, str>>) -> Self {
+        let rendered = Cow::Owned(value.to_string());
+
+        ModinfoValueVersion {
+            value,
+            compat,
+            rendered,
+        }
+    }
+
+    /// Changes the version with `change`, re-rendering it afterwards
+    ///
+    /// All changes to `value` must go through here, so that `rendered` never goes stale.
+    fn update<T>(&mut self, change: impl FnOnce(&mut Version) -> T) -> T {
+        let result = change(&mut self.value);
+        self.rendered = Cow::Owned(self.value.to_string());
+
+        result
+    }
 }
 
 impl fmt::Display for ModinfoValueVersion {
@@ -153,13 +360,23 @@ impl fmt::Display for ModinfoValueVersion {
 
 impl Default for ModinfoValueVersion {
     fn default() -> Self {
-        ModinfoValueVersion {
-            value: Version::new(0, 1, 0),
-            compat: None,
-        }
+        ModinfoValueVersion::new(Version::new(0, 1, 0), None)
     }
 }
 
+/// The fields of a Modinfo, in the canonical order they are written
+const FIELDS: [&str; 9] = [
+    "name",
+    "display_name",
+    "version",
+    "description",
+    "author",
+    "website",
+    "changelog",
+    "priority",
+    "enable_mod",
+];
+
 /// The main struct for the library
 ///
 /// # Fields
@@ -168,8 +385,11 @@ impl Default for ModinfoValueVersion {
 /// * `display_name` - the display name of the modlet (v2 only)
 /// * `version` - the version of the modlet
 /// * `description` - the description of the modlet
-/// * `author` - the author of the modlet
+/// * `author` - the author of the modlet, or the authors joined with `, ` when given as an `<Authors>` list
 /// * `website` - the website of the modlet (v2 only)
+/// * `changelog` - recent changes to the modlet (v2 only, optional)
+/// * `priority` - the numeric load-order priority of the modlet (v2 only, optional)
+/// * `enable_mod` - whether mod managers should enable the modlet (v2 only, optional)
 ///
 /// Additionally, version supports an optional `compat` field which can be used to indicate the game's version for the compatibility string
 ///
@@ -199,18 +419,103 @@ impl Default for ModinfoValueVersion {
 #[derive(Debug, Clone, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Modinfo {
     author: ModinfoValue,
+    authors: Vec<String>,
+    changelog: ModinfoValue,
     description: ModinfoValue,
     display_name: ModinfoValue,
+    display_name_localized: BTreeMap<String, String>,
+    enabled: Option<bool>,
     name: ModinfoValue,
+    priority: Option<i32>,
     version: ModinfoValueVersion,
     website: ModinfoValue,
     meta: ModinfoValueMeta,
 }
 
+#[allow(clippy::to_string_trait_impl)]
 impl ToString for Modinfo {
     fn to_string(&self) -> String {
-        let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', 2);
+        self.to_string_with_options(&WriteOptions::default())
+    }
+}
+
+impl FromStr for Modinfo {
+    type Err = ModinfoError;
+
+    fn from_str(xml: &str) -> Result<Self, Self::Err> {
+        Modinfo::from_str_with_options(xml, &ParseOptions::default())
+    }
+}
+
+/// Builds a minimal, valid V2 Modinfo from a `(name, version, author)` tuple
+///
+/// The remaining fields are filled in as by `Modinfo::scaffold`, and the version is parsed leniently.
+///
+/// ```rust
+/// use modinfo::Modinfo;
+///
+/// let modinfo = Modinfo::from(("MyMod", "1.0.0", "Me"));
+///
+/// assert_eq!(modinfo.name(), Some("MyMod"));
+/// assert_eq!(modinfo.get_version(), &semver::Version::new(1, 0, 0));
+/// assert_eq!(modinfo.author(), Some("Me"));
+/// ```
+impl From<(&str, &str, &str)> for Modinfo {
+    fn from((name, version, author): (&str, &str, &str)) -> Self {
+        let mut modinfo = Modinfo::scaffold(name, author);
+        modinfo.set_value_for("version", version);
+        modinfo
+    }
+}
+
+impl Modinfo {
+    /// Serializes the Modinfo to a ModInfo.xml string using the given `WriteOptions`
+    ///
+    /// `Modinfo::to_string` is equivalent to calling this with `WriteOptions::default()`
+    ///
+    /// ```rust
+    /// use modinfo::{Modinfo, WriteOptions};
+    /// use std::str::FromStr;
+    ///
+    /// let xml = r#"<xml><Author value="Me" /><Name value="SomeMod" /></xml>"#;
+    /// let modinfo = Modinfo::from_str(xml).unwrap();
+    /// let options = WriteOptions { preserve_order: true, ..Default::default() };
+    /// let output = modinfo.to_string_with_options(&options);
+    ///
+    /// assert!(output.find("<Author").unwrap() < output.find("<Name").unwrap());
+    /// ```
+    pub fn to_string_with_options(&self, options: &WriteOptions) -> String {
+        self.render_with_options(options)
+            .expect("serializing a Modinfo to memory cannot fail")
+    }
+
+    /// Serializes the Modinfo using the given `WriteOptions`, see `render`
+    fn render_with_options(&self, options: &WriteOptions) -> Result<String, ModinfoError> {
+        let mut writer = Writer::new(Cursor::new(Vec::new()));
+        let line_ending = match (options.minified, options.line_ending) {
+            (true, _) => None,
+            (false, LineEnding::Preserve) if self.meta.line_ending == LineEnding::CrLf => Some("\r\n"),
+            (false, LineEnding::CrLf) => Some("\r\n"),
+            (false, _) => Some("\n"),
+        };
+        // starts a new, indented line before every markup event but the first; written by hand
+        // rather than by quick_xml so the line ending never touches newlines inside values
+        let newline = |writer: &mut Writer<Cursor<Vec<u8>>>, depth: usize| -> Result<(), ModinfoError> {
+            match line_ending {
+                Some(line_ending) if !writer.get_ref().get_ref().is_empty() => {
+                    writer
+                        .get_mut()
+                        .write_all(format!("{}{}", line_ending, "  ".repeat(depth)).as_bytes())?;
+                    Ok(())
+                }
+                _ => Ok(()),
+            }
+        };
         let is_v2 = ModinfoVersion::V2 == self.meta.version;
+        let quote = match options.quote_style {
+            QuoteStyle::Preserve => self.meta.quote_style,
+            quote_style => quote_style,
+        };
 
         let root_str = match is_v2 {
             true => String::from("xml"),
@@ -218,124 +523,309 @@ impl ToString for Modinfo {
         };
 
         if is_v2 {
-            writer
-                .write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))
-                .unwrap();
+            let (version, standalone) = match &self.meta.declaration {
+                Some(decl) => (decl.version.as_str(), decl.standalone.as_deref()),
+                None => ("1.0", None),
+            };
+            // the output is always UTF-8, whatever encoding the source declared
+            let mut attributes = vec![("version", version), ("encoding", "UTF-8")];
+            if let Some(standalone) = standalone {
+                attributes.push(("standalone", standalone));
+            }
+            let decl = start_tag("xml", &attributes, quote);
+            writer.write_event(Event::Decl(BytesDecl::from_start(decl)))?;
+        }
+        for node in &self.meta.prolog {
+            newline(&mut writer, 0)?;
+            writer.write_event(match node {
+                PrologNode::ProcessingInstruction(content) => Event::PI(BytesText::from_escaped(content.as_str())),
+                PrologNode::DocType(content) => Event::DocType(BytesText::from_escaped(content.as_str())),
+            })?;
+        }
+
+        // namespace and schema attributes emitted by some editors survive a round-trip
+        let root_attributes: Vec<(&str, &str)> = match is_v2 {
+            true => self
+                .meta
+                .root_attributes
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect(),
+            false => Vec::new(),
+        };
+        let root = start_tag(&root_str, &root_attributes, quote);
+        newline(&mut writer, 0)?;
+        writer.write_event(Event::Start(root))?;
+
+        let mut fields: Vec<&str> = match options.preserve_order {
+            true => self.meta.element_order.iter().map(String::as_str).collect(),
+            false => Vec::new(),
+        };
+        for field in FIELDS {
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
         }
-        writer.write_event(Event::Start(BytesStart::new(&root_str))).unwrap();
 
         // inject the attributes here
-        for field in ["name", "display_name", "version", "description", "author", "website"] {
-            if !is_v2 && (field == "website" || field == "display_name") {
+        for field in fields {
+            if !is_v2
+                && matches!(
+                    field,
+                    "website" | "display_name" | "changelog" | "priority" | "enable_mod"
+                )
+            {
+                continue;
+            }
+            // optional elements are only written when they have a value
+            if (field == "changelog" && self.changelog.value.is_none())
+                || (field == "priority" && self.priority.is_none())
+                || (field == "enable_mod" && self.enabled.is_none())
+            {
+                continue;
+            }
+            if options.omit_empty && self.get_owned_value_for(field).is_none() {
+                continue;
+            }
+
+            // several authors are written as `<Authors><Author value="A" /><Author value="B" /></Authors>`
+            if field == "author" && is_v2 && self.authors.len() > 1 {
+                newline(&mut writer, 1)?;
+                writer.write_event(Event::Start(BytesStart::new("Authors")))?;
+                for author in &self.authors {
+                    let elem = start_tag("Author", &[("value", author)], quote);
+
+                    newline(&mut writer, 2)?;
+                    writer.write_event(Event::Empty(elem))?;
+                }
+                newline(&mut writer, 1)?;
+                writer.write_event(Event::End(BytesEnd::new("Authors")))?;
                 continue;
             }
 
             let field_name = field.to_owned().to_case(Case::Pascal);
-            let mut elem = BytesStart::new(field_name);
-            let value = match field {
-                "version" => self.get_version().to_string(),
-                _ => match self.get_value_for(field) {
-                    Some(value) => value.to_string(),
-                    None => String::new(),
-                },
-            };
+            let value = self.get_owned_value_for(field).unwrap_or_default();
 
-            elem.push_attribute(attributes::Attribute {
-                key: quick_xml::name::QName(b"value"),
-                value: Cow::from(value.clone().into_bytes()),
-            });
+            // a description read from CDATA is written back as CDATA, unless it can't be represented as one
+            if field == "description" && self.meta.cdata_description && !value.contains("]]>") {
+                newline(&mut writer, 1)?;
+                writer.write_event(Event::Start(BytesStart::new(field_name.as_str())))?;
+                writer.write_event(Event::CData(BytesCData::new(value)))?;
+                writer.write_event(Event::End(BytesEnd::new(field_name.as_str())))?;
+                continue;
+            }
 
-            if field == "version" && self.version.compat.is_some() {
-                elem.push_attribute(attributes::Attribute {
-                    key: quick_xml::name::QName(b"compat"),
-                    value: Cow::from(self.version.compat.as_ref().unwrap().as_bytes()),
-                });
+            let mut attributes = vec![("value", value.as_str())];
+            if field == "version" {
+                if let Some(compat) = &self.version.compat {
+                    attributes.push(("compat", compat.as_ref()));
+                }
             };
 
-            writer.write_event(Event::Empty(elem)).unwrap();
+            newline(&mut writer, 1)?;
+            writer.write_event(Event::Empty(start_tag(&field_name, &attributes, quote)))?;
+
+            if field == "display_name" {
+                for (lang, value) in &self.display_name_localized {
+                    let elem = start_tag("DisplayName", &[("value", value), ("lang", lang)], quote);
+
+                    newline(&mut writer, 1)?;
+                    writer.write_event(Event::Empty(elem))?;
+                }
+            }
         }
 
-        writer.write_event(Event::End(BytesEnd::new(&root_str))).unwrap();
+        newline(&mut writer, 0)?;
+        writer.write_event(Event::End(BytesEnd::new(&root_str)))?;
+
+        for content in &self.meta.epilogue {
+            newline(&mut writer, 0)?;
+            writer.write_event(Event::PI(BytesText::from_escaped(content.as_str())))?;
+        }
 
-        String::from_utf8(writer.into_inner().into_inner()).unwrap()
+        String::from_utf8(writer.into_inner().into_inner()).map_err(|_| ModinfoError::WriteError)
     }
-}
 
-impl FromStr for Modinfo {
-    type Err = ModinfoError;
+    /// Parses a `'static` ModInfo.xml document, such as a template embedded with `include_str!`
+    ///
+    /// The document is parsed as by `from_str`, after which values which appear verbatim in `xml`
+    /// (i.e. that needed no unescaping) are switched to borrow from it, so the resulting Modinfo
+    /// holds no owned copies of them. Parsing itself still allocates as usual.
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    ///
+    /// static TEMPLATE: &str = r#"<xml><Name value="SomeMod" /><Author value="Me" /></xml>"#;
+    ///
+    /// let modinfo = Modinfo::from_static(TEMPLATE).unwrap();
+    ///
+    /// assert_eq!(modinfo.name(), Some("SomeMod"));
+    /// ```
+    pub fn from_static(xml: &'static str) -> Result<Self, ModinfoError> {
+        let mut modinfo = Modinfo::from_str(xml)?;
+        let mut reader = Reader::from_str(xml);
 
-    fn from_str(xml: &str) -> Result<Self, Self::Err> {
+        loop {
+            match reader.read_event()? {
+                Event::Eof => break,
+                Event::Start(e) | Event::Empty(e) => {
+                    let field = String::from_utf8_lossy(e.name().as_ref()).to_case(Case::Snake);
+
+                    for attribute in e.attributes().flatten() {
+                        let value = match (field.as_str(), attribute.key.as_ref()) {
+                            ("author", b"value") => &mut modinfo.author.value,
+                            ("changelog", b"value") => &mut modinfo.changelog.value,
+                            ("description", b"value") => &mut modinfo.description.value,
+                            ("display_name", b"value") => &mut modinfo.display_name.value,
+                            ("name", b"value") => &mut modinfo.name.value,
+                            ("website", b"value") => &mut modinfo.website.value,
+                            ("version", b"compat") => &mut modinfo.version.compat,
+                            _ => continue,
+                        };
+                        // only the value of this very element is borrowed, and only if it is the one that was kept
+                        if let Cow::Borrowed(raw) = attribute.unescape_value()? {
+                            if let Some(raw) = subslice(xml, raw).filter(|raw| value.as_deref() == Some(*raw)) {
+                                *value = Some(Cow::Borrowed(raw));
+                            }
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        Ok(modinfo)
+    }
+
+    /// Parses a ModInfo.xml document from an already configured `quick_xml::Reader`
+    ///
+    /// The reader is used as-is, so any configuration (such as `trim_text`) is left to the caller
+    ///
+    /// ```rust
+    /// use modinfo::Modinfo;
+    /// use quick_xml::reader::Reader;
+    ///
+    /// let mut reader = Reader::from_str(r#"<xml><Name>  SomeMod  </Name></xml>"#);
+    /// reader.trim_text(true);
+    ///
+    /// let modinfo = Modinfo::from_reader_config(reader).unwrap();
+    ///
+    /// assert_eq!(modinfo.name(), Some("SomeMod"));
+    /// ```
+    pub fn from_reader_config<R: BufRead>(reader: Reader<R>) -> Result<Self, ModinfoError> {
+        Modinfo::from_reader_with_options(reader, &ParseOptions::default())
+    }
+
+    fn from_reader_with_options<R: BufRead>(
+        mut reader: Reader<R>,
+        options: &ParseOptions,
+    ) -> Result<Self, ModinfoError> {
         let mut modinfo = Modinfo::default();
         let mut buf: Vec<u8> = Vec::new();
-        let mut reader = Reader::from_str(xml);
-        reader.trim_text(true);
+
+        // the depth of the current element, the root element is at depth 1
+        let mut depth = 0;
+        // a child element whose value may be given as text content, e.g. `<Name>SomeMod</Name>`
+        let mut pending: Option<(Vec<u8>, BTreeMap<String, String>)> = None;
+        // the quotes around the first child attribute, assumed to be used throughout the file
+        let mut quote_style: Option<QuoteStyle> = None;
+        // inside an `<Authors>` container, whose `<Author>` entries are collected into a list
+        let mut in_authors = false;
 
         loop {
             match reader.read_event_into(&mut buf) {
-                Err(e) => panic!("Error at position {}: {:?}", reader.buffer_position(), e),
+                Err(e) => {
+                    return Err(ModinfoError::XMLErrorAt {
+                        position: reader.buffer_position(),
+                        source: e,
+                    })
+                }
                 Ok(Event::Eof) => break,
+                Ok(Event::Decl(e)) => modinfo.meta.declaration = Some(XmlDeclaration::from_decl(&e)?),
+                // the root tag is only set once the root element starts, so anything after it is trailing markup
+                Ok(Event::PI(e)) if depth == 0 && !modinfo.meta.root_tag.is_empty() => modinfo
+                    .meta
+                    .epilogue
+                    .push(String::from_utf8_lossy(e.as_ref()).into_owned()),
+                Ok(Event::PI(e)) if depth == 0 => modinfo.meta.prolog.push(PrologNode::ProcessingInstruction(
+                    String::from_utf8_lossy(e.as_ref()).into_owned(),
+                )),
+                // a DOCTYPE is only valid before the root element, a trailing one is dropped
+                Ok(Event::DocType(e)) if depth == 0 && modinfo.meta.root_tag.is_empty() => modinfo
+                    .meta
+                    .prolog
+                    .push(PrologNode::DocType(String::from_utf8_lossy(e.as_ref()).into_owned())),
                 // Root Element
-                Ok(Event::Start(e)) => {
+                Ok(Event::Start(e)) if depth == 0 => {
+                    depth += 1;
+                    modinfo.meta.root_tag = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+                    modinfo.meta.root_attributes = parse_root_attributes(e.attributes(), options.max_attribute_len)?;
                     modinfo.meta.version = match e.name().as_ref() {
                         b"xml" => ModinfoVersion::V2,
+                        root if options.accept_root.iter().any(|r| r.as_bytes() == root) => ModinfoVersion::V2,
                         _ => ModinfoVersion::V1,
                     }
                 }
-                // Child Elements (because they have no children)
-                Ok(Event::Empty(e)) => {
-                    let attributes = parse_attributes(e.attributes());
-                    let value = attributes["value"].clone();
+                // A list of authors, e.g. `<Authors><Author value="A" /><Author value="B" /></Authors>`
+                Ok(Event::Start(e)) if depth == 1 && e.name().as_ref() == b"Authors" => {
+                    depth += 1;
+                    in_authors = true;
+                    modinfo.authors.clear();
+                    *modinfo.meta.tag_counts.entry(String::from("Authors")).or_default() += 1;
+                    if !modinfo.meta.element_order.iter().any(|field| field == "author") {
+                        modinfo.meta.element_order.push(String::from("author"));
+                    }
+                }
+                Ok(Event::End(e)) if in_authors && e.name().as_ref() == b"Authors" => {
+                    depth -= 1;
+                    in_authors = false;
+                }
+                // Child Elements with children (legacy files may store the value as text)
+                Ok(Event::Start(e)) => {
+                    depth += 1;
+                    quote_style = quote_style.or_else(|| detect_quote_style(&e));
+                    let attributes = parse_attributes(e.attributes(), options.max_attribute_len)?;
 
-                    match e.name().as_ref() {
-                        b"Author" => {
-                            modinfo.author = ModinfoValue {
-                                value: Some(value.into()),
-                            }
+                    match attributes.contains_key("value") {
+                        true if in_authors && e.name().as_ref() == b"Author" => {
+                            parse_author_entry(&mut modinfo, attributes)?
                         }
-                        b"Description" => {
-                            modinfo.description = ModinfoValue {
-                                value: Some(value.into()),
-                            }
-                        }
-                        b"DisplayName" => {
-                            modinfo.display_name = ModinfoValue {
-                                value: Some(value.into()),
-                            }
-                        }
-                        b"Name" => {
-                            if modinfo.display_name.value.is_none() {
-                                modinfo.display_name = ModinfoValue {
-                                    value: Some(value.clone().to_case(Case::Title).into()),
-                                }
-                            }
-
-                            modinfo.name = ModinfoValue {
-                                value: Some(value.into()),
-                            }
+                        true => parse_element(&mut modinfo, e.name().as_ref(), attributes, options)?,
+                        false => pending = Some((e.name().as_ref().to_vec(), attributes)),
+                    }
+                }
+                Ok(Event::Text(e)) => {
+                    if let Some((_, attributes)) = pending.as_mut() {
+                        check_size(e.len(), options.max_attribute_len)?;
+                        attributes.insert("value".to_owned(), e.unescape()?.into_owned());
+                    }
+                }
+                // CDATA content is kept verbatim, e.g. markup in `<Description><![CDATA[<b>bold</b>]]></Description>`
+                Ok(Event::CData(e)) => {
+                    if let Some((name, attributes)) = pending.as_mut() {
+                        check_size(e.len(), options.max_attribute_len)?;
+                        modinfo.meta.cdata_description |= name == b"Description";
+                        attributes.insert("value".to_owned(), String::from_utf8_lossy(&e).into_owned());
+                    }
+                }
+                Ok(Event::End(_)) => {
+                    depth -= 1;
+                    if let Some((name, attributes)) = pending.take() {
+                        // text-form entries of a list, e.g. `<Authors><Author>A</Author></Authors>`
+                        match in_authors && name == b"Author" {
+                            true => parse_author_entry(&mut modinfo, attributes)?,
+                            false => parse_element(&mut modinfo, &name, attributes, options)?,
                         }
-                        b"Version" => {
-                            let mut compat = None;
+                    }
+                }
+                // Child Elements (because they have no children)
+                Ok(Event::Empty(e)) => {
+                    quote_style = quote_style.or_else(|| detect_quote_style(&e));
+                    let attributes = parse_attributes(e.attributes(), options.max_attribute_len)?;
 
-                            if attributes.contains_key("compat") {
-                                compat = Some(attributes["compat"].clone().into());
-                            }
-                            modinfo.version = ModinfoValueVersion {
-                                value: match lenient_semver::parse_into::<Version>(&value) {
-                                    Ok(result) => result.clone(),
-                                    Err(err) => {
-                                        lenient_semver::parse_into::<Version>(format!("0.0.0+{}", err).as_ref())
-                                            .unwrap()
-                                    }
-                                },
-                                compat,
-                            }
-                        }
-                        b"Website" => {
-                            modinfo.website = ModinfoValue {
-                                value: Some(value.into()),
-                            }
-                        }
-                        _ => (),
+                    match in_authors && e.name().as_ref() == b"Author" {
+                        true => parse_author_entry(&mut modinfo, attributes)?,
+                        false => parse_element(&mut modinfo, e.name().as_ref(), attributes, options)?,
                     }
                 }
                 Ok(_) => (),
@@ -344,21 +834,300 @@ impl FromStr for Modinfo {
             buf.clear();
         }
 
+        modinfo.meta.quote_style = quote_style.unwrap_or_default();
+
+        // the default version stands in for a missing <Version>, which `validate` reports
+        modinfo.meta.version_missing =
+            !modinfo.meta.root_tag.is_empty() && !modinfo.meta.tag_counts.contains_key("Version");
+
         Ok(modinfo)
     }
 }
 
-fn parse_attributes(input: attributes::Attributes) -> HashMap<String, String> {
-    let mut attributes = HashMap::new();
+/// Builds a `<name key="value" ...>` tag, escaping each value and quoting it with `quote`
+///
+/// quick_xml always writes double quotes, so the tag content is assembled here for either style.
+fn start_tag(name: &str, attributes: &[(&str, &str)], quote: QuoteStyle) -> BytesStart<'static> {
+    let quote = match quote {
+        QuoteStyle::Single => '\'',
+        _ => '"',
+    };
+    let mut content = String::from(name);
+
+    for (key, value) in attributes {
+        content.push_str(&format!(
+            " {}={}{}{}",
+            key,
+            quote,
+            quick_xml::escape::escape(value),
+            quote
+        ));
+    }
+
+    BytesStart::from_content(content, name.len())
+}
+
+/// Re-borrows `part`, a slice taken from `whole`, with the lifetime of `whole`
+///
+/// Returns `None` if `part` does not lie within `whole`.
+fn subslice<'a>(whole: &'a str, part: &str) -> Option<&'a str> {
+    let offset = (part.as_ptr() as usize).checked_sub(whole.as_ptr() as usize)?;
+
+    whole.get(offset..offset + part.len())
+}
+
+/// Returns `LineEnding::CrLf` if most of the line endings in `text` are `\r\n`, otherwise `LineEnding::Lf`
+fn detect_line_ending(text: &str) -> LineEnding {
+    let crlf = text.matches("\r\n").count();
+    let lf = text.matches('\n').count() - crlf;
+
+    match crlf > lf {
+        true => LineEnding::CrLf,
+        false => LineEnding::Lf,
+    }
+}
+
+/// Returns the quote style of the first attribute of `elem`, if it has any attributes
+fn detect_quote_style(elem: &BytesStart) -> Option<QuoteStyle> {
+    let raw = elem.attributes_raw();
+    let equals = raw.iter().position(|&byte| byte == b'=')?;
+
+    match raw[equals + 1..].iter().find(|byte| !byte.is_ascii_whitespace())? {
+        b'\'' => Some(QuoteStyle::Single),
+        _ => Some(QuoteStyle::Double),
+    }
+}
+
+/// Stores the value of a child element (given by its `attributes`) in the matching Modinfo field
+fn parse_element(
+    modinfo: &mut Modinfo,
+    name: &[u8],
+    attributes: BTreeMap<String, String>,
+    options: &ParseOptions,
+) -> Result<(), ModinfoError> {
+    let value = attributes.get("value").cloned().unwrap_or_default();
+    let tag = String::from_utf8_lossy(name);
+    let field = tag.to_case(Case::Snake);
+
+    // localized entries (e.g. `<DisplayName lang="de" />`) are kept apart from the element they translate
+    if !attributes.contains_key("lang") {
+        *modinfo.meta.tag_counts.entry(tag.to_string()).or_default() += 1;
+        modinfo.meta.raw_attributes.insert(tag.into_owned(), attributes.clone());
+    }
+
+    if FIELDS.contains(&field.as_str()) && !modinfo.meta.element_order.contains(&field) {
+        modinfo.meta.element_order.push(field);
+    }
+
+    match name {
+        b"Author" => {
+            modinfo.authors.clear();
+            modinfo.author = ModinfoValue {
+                value: Some(value.into()),
+            }
+        }
+        b"Changelog" => {
+            modinfo.changelog = ModinfoValue {
+                value: Some(value.into()),
+            }
+        }
+        b"Description" => {
+            modinfo.description = ModinfoValue {
+                value: Some(value.into()),
+            }
+        }
+        b"DisplayName" => match attributes.get("lang") {
+            Some(lang) => {
+                modinfo.display_name_localized.insert(lang.clone(), value);
+            }
+            None => {
+                modinfo.display_name = ModinfoValue {
+                    value: Some(value.into()),
+                };
+                modinfo.meta.display_name_derived = false;
+            }
+        },
+        b"Name" => {
+            if modinfo.display_name.value.is_none() {
+                modinfo.display_name = ModinfoValue {
+                    value: Some(value.clone().to_case(Case::Title).into()),
+                };
+                modinfo.meta.display_name_derived = true;
+            }
+
+            modinfo.name = ModinfoValue {
+                value: Some(value.into()),
+            }
+        }
+        b"EnableMod" => modinfo.enabled = Some(parse_enabled(&value)?),
+        b"Priority" => {
+            let priority = value.trim().parse().map_err(|_| ModinfoError::InvalidPriority(value))?;
+            modinfo.priority = Some(priority);
+        }
+        b"Version" => {
+            let mut compat = None;
+
+            if attributes.contains_key("compat") {
+                compat = Some(attributes["compat"].clone().into());
+            }
+            let (version, error) = parse_version_with_options(&value, options)?;
+            modinfo.meta.version_parse_error = error;
+            modinfo.version = ModinfoValueVersion::new(version, compat)
+        }
+        b"Website" => {
+            modinfo.website = ModinfoValue {
+                value: Some(value.into()),
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Adds an `<Author>` entry of an `<Authors>` container, keeping the flat `author` as the joined list
+fn parse_author_entry(modinfo: &mut Modinfo, attributes: BTreeMap<String, String>) -> Result<(), ModinfoError> {
+    let value = attributes.get("value").cloned().unwrap_or_default();
 
-    input.map(|a| a.unwrap()).for_each(|a| {
+    modinfo.authors.push(value);
+    modinfo.author = ModinfoValue {
+        value: Some(modinfo.authors.join(", ").into()),
+    };
+
+    Ok(())
+}
+
+/// Returns `ModinfoError::TooLarge` if `size` exceeds the (optional) `limit`
+fn check_size(size: usize, limit: Option<usize>) -> Result<(), ModinfoError> {
+    match limit {
+        Some(limit) if size > limit => Err(ModinfoError::TooLarge { size, limit }),
+        _ => Ok(()),
+    }
+}
+
+/// Parses an `<EnableMod>` value, accepting `true`/`false`/`1`/`0` case-insensitively
+fn parse_enabled(value: &str) -> Result<bool, ModinfoError> {
+    match value.trim().to_lowercase().as_str() {
+        "true" | "1" => Ok(true),
+        "false" | "0" => Ok(false),
+        _ => Err(ModinfoError::InvalidEnableMod(value.to_owned())),
+    }
+}
+
+/// Collects an element's attributes, keyed by lowercased name
+///
+/// A `BTreeMap` is used so that attributes always iterate in the same (sorted) order,
+/// which keeps anything derived from them reproducible.
+/// Single- and double-quoted values (`value='SomeMod'` and `value="SomeMod"`) parse identically.
+fn parse_attributes(
+    input: attributes::Attributes,
+    max_len: Option<usize>,
+) -> Result<BTreeMap<String, String>, ModinfoError> {
+    let mut attributes = BTreeMap::new();
+
+    for attribute in input {
+        let a = attribute.map_err(quick_xml::Error::InvalidAttr)?;
+        check_size(a.value.len(), max_len)?;
         let key: String = String::from_utf8_lossy(a.key.as_ref()).to_lowercase();
-        let value = String::from_utf8(a.value.into_owned()).unwrap();
+        let value = a.unescape_value()?.into_owned();
 
         attributes.insert(key, value);
-    });
+    }
+
+    Ok(attributes)
+}
+
+/// Collects the root element's attributes (e.g. `xmlns:xsi`), keeping their case and order
+fn parse_root_attributes(
+    input: attributes::Attributes,
+    max_len: Option<usize>,
+) -> Result<Vec<(String, String)>, ModinfoError> {
+    let mut attributes = Vec::new();
+
+    for attribute in input {
+        let a = attribute.map_err(quick_xml::Error::InvalidAttr)?;
+        check_size(a.value.len(), max_len)?;
+        let key = String::from_utf8_lossy(a.key.as_ref()).into_owned();
+        let value = a.unescape_value()?.into_owned();
+
+        attributes.push((key, value));
+    }
+
+    Ok(attributes)
+}
+
+/// Parses raw bytes into a Modinfo without ever panicking
+///
+/// This is intended for untrusted input (and fuzzing): any malformed XML, invalid attribute
+/// or non-UTF-8 content is reported as a `ModinfoError` rather than a panic.
+///
+/// ```rust
+/// assert!(modinfo::parse_lenient(br#"<xml><Name value="SomeMod" /></xml>"#).is_ok());
+/// assert!(modinfo::parse_lenient(b"<xml><Name value=\"\xff\" /></xml>").is_err());
+/// ```
+pub fn parse_lenient(bytes: &[u8]) -> Result<Modinfo, ModinfoError> {
+    let xml = std::str::from_utf8(bytes).map_err(|err| quick_xml::Error::NonDecodable(Some(err)))?;
+
+    Modinfo::from_str(xml)
+}
+
+/// Parses raw bytes into a Modinfo, also returning the detected ModInfo.xml format
+///
+/// Decoding and error handling are the same as `parse_lenient`.
+///
+/// ```rust
+/// use modinfo::ModinfoVersion;
+///
+/// let (modinfo, version) = modinfo::sniff(br#"<ModInfo><Name value="SomeMod" /></ModInfo>"#).unwrap();
+///
+/// assert_eq!(version, ModinfoVersion::V1);
+/// assert_eq!(modinfo.name(), Some("SomeMod"));
+/// ```
+pub fn sniff(bytes: &[u8]) -> Result<(Modinfo, ModinfoVersion), ModinfoError> {
+    let modinfo = parse_lenient(bytes)?;
+    let version = modinfo.get_modinfo_version();
+
+    Ok((modinfo, version))
+}
+
+/// Splits a legacy combined name such as `SomeMod (Some Cool Mod)` into the internal name and display name
+///
+/// The display name is the trailing parenthesized group (which may itself contain parentheses).
+/// Input without one, or where either part would be empty, is returned trimmed as the name alone.
+///
+/// ```rust
+/// assert_eq!(
+///     modinfo::split_name_display("SomeMod (Cool Mod)"),
+///     ("SomeMod".to_owned(), Some("Cool Mod".to_owned()))
+/// );
+/// assert_eq!(modinfo::split_name_display("SomeMod"), ("SomeMod".to_owned(), None));
+/// ```
+pub fn split_name_display(input: &str) -> (String, Option<String>) {
+    let input = input.trim();
+
+    if input.ends_with(')') {
+        // find the parenthesis which opens the trailing group
+        let mut depth = 0;
+        for (index, c) in input.char_indices().rev() {
+            match c {
+                ')' => depth += 1,
+                '(' => depth -= 1,
+                _ => (),
+            }
+
+            if depth == 0 {
+                let name = input[..index].trim();
+                let display_name = input[index + 1..input.len() - 1].trim();
 
-    attributes
+                if !name.is_empty() && !display_name.is_empty() {
+                    return (name.to_owned(), Some(display_name.to_owned()));
+                }
+                break;
+            }
+        }
+    }
+
+    (input.to_owned(), None)
 }
 
 /// Parses a Modinfo.xml file and produces a Modinfo struct
@@ -376,40 +1145,268 @@ fn parse_attributes(input: attributes::Attributes) -> HashMap<String, String> {
 /// ## Possible ModinfoError
 ///
 /// * `ModinfoError::FsNotFound` - the file does not exist
+/// * `ModinfoError::FsIsDirectory` - the path is a directory (see `parse_mod_dir` for mod directories)
 /// * `ModinfoError::IoError` - an I/O error occurred
 /// * `ModinfoError::NoModinfoAuthor` - no Author tag found (required)
 /// * `ModinfoError::NoModinfoDescription` - no Description tag found (required)
 /// * `ModinfoError::NoModinfoName` - no Name tag found (required)
 /// * `ModinfoError::NoModinfoVersion` - no Version value found (required)
 /// * `ModinfoError::XMLError` - an error occurred while trying to parse the XML (possibly invalid XML structure?)
+/// * `ModinfoError::XMLErrorAt` - the XML is malformed, `position` holds the byte offset of the defect
 ///
 pub fn parse(file: impl AsRef<Path>) -> Result<Modinfo, ModinfoError> {
-    let modinfo = match Path::try_exists(file.as_ref()) {
-        Ok(true) => Modinfo::from_str(fs::read_to_string(&file)?.as_ref()),
-        Ok(false) => return Err(ModinfoError::FsNotFound),
-        Err(err) => return Err(ModinfoError::IoError(err)),
+    parse_with_options(file, &ParseOptions::default())
+}
+
+/// Parses a ModInfo.xml string, with the same required-field checks as `parse`
+///
+/// Unlike `Modinfo::from_str`, a document missing its author, description, name or version
+/// is an error (see `Modinfo::validate`).
+///
+/// ```rust
+/// use modinfo::ModinfoError;
+///
+/// let xml = r#"<xml><Name value="SomeMod" /><Version value="1.0.0" /><Description value="Does things" /></xml>"#;
+///
+/// assert!(matches!(modinfo::parse_str(xml), Err(ModinfoError::NoModinfoAuthor)));
+/// ```
+pub fn parse_str(xml: &str) -> Result<Modinfo, ModinfoError> {
+    let modinfo = Modinfo::from_str(xml)?;
+    modinfo.validate()?;
+
+    Ok(modinfo)
+}
+
+/// Parses a Modinfo.xml file using the given `ParseOptions`
+///
+/// Works like `parse`, but `options.required_fields` decides which missing fields are errors.
+/// Required fields without a dedicated error are reported as `ModinfoError::NoModinfoField`.
+pub fn parse_with_options(file: impl AsRef<Path>, options: &ParseOptions) -> Result<Modinfo, ModinfoError> {
+    let xml = read_file(file.as_ref(), options)?;
+
+    parse_file_contents(file.as_ref(), &xml, options)
+}
+
+/// Reads a ModInfo.xml file, checking that it exists, is not a directory and is within `max_total_len`
+fn read_file(file: &Path, options: &ParseOptions) -> Result<String, ModinfoError> {
+    match Path::try_exists(file) {
+        Ok(true) if file.is_dir() => Err(ModinfoError::FsIsDirectory(file.to_path_buf())),
+        Ok(true) => {
+            // avoid reading an oversized file into memory at all
+            check_size(fs::metadata(file)?.len() as usize, options.max_total_len)?;
+            Ok(fs::read_to_string(file)?)
+        }
+        Ok(false) => Err(ModinfoError::FsNotFound),
+        Err(err) => Err(ModinfoError::IoError(err)),
+    }
+}
+
+/// Parses the contents `xml` read from `file`, checking the required fields
+fn parse_file_contents(file: &Path, xml: &str, options: &ParseOptions) -> Result<Modinfo, ModinfoError> {
+    let mut modinfo = Modinfo::from_str_with_options(xml, options)?;
+    modinfo.validate_required(&options.required_fields)?;
+
+    // store the original file path in the metadata
+    modinfo.meta.path = file.to_path_buf();
+
+    Ok(modinfo)
+}
+
+/// Parses a Modinfo.xml file like `parse`, also returning warnings about suspicious values
+///
+/// Warnings are reported for values which parsed, but probably not as intended:
+///
+/// * a version which is not strict semver (e.g. `1.2`), or which could not be parsed at all
+/// * a website which is not an `http://` or `https://` URL
+/// * a V2 file without a `<DisplayName>`, whose display name was derived from `Name`
+///
+pub fn parse_with_warnings(file: impl AsRef<Path>) -> Result<(Modinfo, Vec<String>), ModinfoError> {
+    let modinfo = parse(file)?;
+    let warnings = modinfo.parse_warnings();
+
+    Ok((modinfo, warnings))
+}
+
+/// Parses a Modinfo.xml file like `parse`, also returning `ParseStats` for profiling
+pub fn parse_with_stats(file: impl AsRef<Path>) -> Result<(Modinfo, ParseStats), ModinfoError> {
+    let options = ParseOptions::default();
+    let start = Instant::now();
+    let xml = read_file(file.as_ref(), &options)?;
+    let modinfo = parse_file_contents(file.as_ref(), &xml, &options)?;
+    let duration = start.elapsed();
+
+    // an `<Authors>` container is counted through its entries only
+    let element_count = modinfo
+        .meta
+        .tag_counts
+        .iter()
+        .filter(|(tag, _)| tag.as_str() != "Authors")
+        .map(|(_, count)| count)
+        .sum::<usize>()
+        + modinfo.display_name_localized.len()
+        + modinfo.authors.len();
+    let stats = ParseStats {
+        bytes_read: xml.len(),
+        element_count,
+        duration,
     };
 
-    match modinfo {
-        Ok(mut modinfo) => {
-            if modinfo.author.value.is_none() {
-                return Err(ModinfoError::NoModinfoAuthor);
-            }
-            if modinfo.description.value.is_none() {
-                return Err(ModinfoError::NoModinfoDescription);
+    Ok((modinfo, stats))
+}
+
+/// Parses many ModInfo.xml strings at once
+///
+/// Each input is parsed independently with `Modinfo::from_str`, so a failure in one
+/// input does not prevent the others from being parsed.  Results are returned in input order.
+///
+/// ```rust
+/// let results = modinfo::parse_all(vec![
+///     r#"<xml><Name value="SomeMod" /></xml>"#.to_owned(),
+///     r#"<xml><Name value="Broken" /></ModInfo>"#.to_owned(),
+/// ]);
+///
+/// assert!(results[0].is_ok());
+/// assert!(results[1].is_err());
+/// ```
+pub fn parse_all<I: IntoIterator<Item = String>>(inputs: I) -> Vec<Result<Modinfo, ModinfoError>> {
+    inputs.into_iter().map(|xml| Modinfo::from_str(&xml)).collect()
+}
+
+/// Parses a stream of concatenated ModInfo.xml documents
+///
+/// Each document starts at its XML declaration (if any) or root element, and ends with its
+/// root element; whitespace, comments and other content between documents is ignored.
+///
+/// ```rust
+/// let xml = r#"
+///     <?xml version="1.0" encoding="UTF-8"?>
+///     <xml><Name value="FirstMod" /></xml>
+///     <?xml version="1.0" encoding="UTF-8"?>
+///     <xml><Name value="SecondMod" /></xml>
+/// "#;
+/// let modinfos = modinfo::parse_multi(xml).unwrap();
+///
+/// assert_eq!(modinfos.len(), 2);
+/// assert_eq!(modinfos[1].name(), Some("SecondMod"));
+/// ```
+pub fn parse_multi(xml: &str) -> Result<Vec<Modinfo>, ModinfoError> {
+    let mut modinfos = Vec::new();
+    let mut reader = Reader::from_str(xml);
+    let mut depth = 0;
+    let mut start = None;
+
+    loop {
+        let position = reader.buffer_position();
+        let event = reader.read_event().map_err(|source| ModinfoError::XMLErrorAt {
+            position: reader.buffer_position(),
+            source,
+        })?;
+
+        match event {
+            Event::Eof => break,
+            Event::Decl(_) if depth == 0 => start = Some(position),
+            Event::Start(_) => {
+                if depth == 0 {
+                    start.get_or_insert(position);
+                }
+                depth += 1;
             }
-            if modinfo.name.value.is_none() {
-                return Err(ModinfoError::NoModinfoName);
+            Event::End(_) => {
+                depth -= 1;
+                if depth == 0 {
+                    let start = start.take().unwrap_or(position);
+                    modinfos.push(Modinfo::from_str(&xml[start..reader.buffer_position()])?);
+                }
             }
-            if modinfo.version.value.to_string().is_empty() {
-                return Err(ModinfoError::NoModinfoVersion);
+            Event::Empty(_) if depth == 0 => {
+                let start = start.take().unwrap_or(position);
+                modinfos.push(Modinfo::from_str(&xml[start..reader.buffer_position()])?);
             }
+            _ => (),
+        }
+    }
 
-            // store the original file path in the metadata
-            modinfo.meta.path = file.as_ref().to_path_buf();
+    Ok(modinfos)
+}
 
-            Ok(modinfo)
+/// Finds and parses the ModInfo.xml file inside of a mod directory
+///
+/// 7 Days to Die expects each mod to live in its own folder containing a `ModInfo.xml` file,
+/// however the casing of the filename varies between mods, so the match is case-insensitive.
+/// An exact `ModInfo.xml` is preferred; otherwise the first matching filename in sorted order is used.
+///
+/// # Arguments
+///
+/// * `dir` - a Path-like object pointing to a mod directory
+///
+/// # Returns
+///
+/// A `Result` containing either a `Modinfo` struct or a `ModinfoError`
+///
+/// ## Possible ModinfoError
+///
+/// * `ModinfoError::FsDirNotFound` - the directory does not exist, or is not a directory
+/// * `ModinfoError::NoModinfo` - no modinfo.xml file was found in the directory
+/// * any error returned by `parse`
+///
+pub fn parse_mod_dir(dir: impl AsRef<Path>) -> Result<Modinfo, ModinfoError> {
+    if !dir.as_ref().is_dir() {
+        return Err(ModinfoError::FsDirNotFound(dir.as_ref().to_path_buf()));
+    }
+
+    let mut candidates = Vec::new();
+
+    for entry in fs::read_dir(dir.as_ref())? {
+        let path = entry?.path();
+
+        if path.is_file()
+            && path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().eq_ignore_ascii_case("modinfo.xml"))
+        {
+            candidates.push(path);
         }
-        Err(err) => Err(err),
     }
+
+    // read_dir order is platform-dependent, so pick deterministically
+    candidates.sort();
+
+    let path = match candidates.iter().position(|path| path.ends_with("ModInfo.xml")) {
+        Some(index) => candidates.swap_remove(index),
+        None => candidates.into_iter().next().ok_or(ModinfoError::NoModinfo)?,
+    };
+
+    parse(path)
+}
+
+/// Lazily parses every mod directory inside of a Mods folder
+///
+/// Each subdirectory of `root` is parsed with `parse_mod_dir` only when the iterator reaches it,
+/// so callers can stop early (e.g. with `find`) without parsing the remaining mods.
+/// Subdirectories are visited in sorted order, and each item pairs the mod directory with its result.
+///
+/// If `root` is not a readable directory, a single `ModinfoError::FsDirNotFound` item is returned.
+///
+/// ```rust,no_run
+/// let found = modinfo::iter_dir("Mods")
+///     .find(|(_, modinfo)| modinfo.as_ref().is_ok_and(|modinfo| modinfo.name() == Some("SomeMod")));
+/// ```
+pub fn iter_dir(root: impl AsRef<Path>) -> impl Iterator<Item = (PathBuf, Result<Modinfo, ModinfoError>)> {
+    let root = root.as_ref().to_path_buf();
+    let (mut dirs, error) = match fs::read_dir(&root) {
+        Ok(entries) => (
+            entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.is_dir())
+                .collect::<Vec<_>>(),
+            None,
+        ),
+        Err(_) => (Vec::new(), Some((root.clone(), Err(ModinfoError::FsDirNotFound(root))))),
+    };
+    dirs.sort();
+
+    error.into_iter().chain(dirs.into_iter().map(|dir| {
+        let modinfo = parse_mod_dir(&dir);
+        (dir, modinfo)
+    }))
 }
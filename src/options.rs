@@ -0,0 +1,117 @@
+use super::ModinfoField;
+use semver::Version;
+
+/// Options which control how a ModInfo.xml document is parsed
+///
+/// # Example
+///
+/// ```rust
+/// use modinfo::ParseOptions;
+///
+/// let options = ParseOptions { trim_text: false, ..Default::default() };
+///
+/// assert!(!options.trim_text);
+/// ```
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct ParseOptions {
+    /// Trim leading and trailing whitespace from text nodes (default: `true`)
+    pub trim_text: bool,
+    /// Additional root element names which are accepted and treated as V2 (default: empty)
+    ///
+    /// Roots other than `<xml>`, `<ModInfo>` or one of these are still tolerated, but are treated as V1.
+    pub accept_root: Vec<String>,
+    /// The version to use when a `<Version>` value cannot be parsed (default: `0.0.0`)
+    ///
+    /// The fallback is used as-is, and the parse error is available from `Modinfo::version_parse_error`.
+    /// `None` returns a `ModinfoError::InvalidVersionString` instead.
+    pub version_fallback: Option<Version>,
+    /// Require versions to be strict `MAJOR.MINOR.PATCH` semver (default: `false`)
+    ///
+    /// When enabled, lenient forms such as `1.2` or `v1.2.3` return `ModinfoError::InvalidVersionString`
+    /// and `version_fallback` is not used.
+    pub strict_semver: bool,
+    /// The fields `parse_with_options` requires to be present (default: author, description, name and version)
+    pub required_fields: Vec<ModinfoField>,
+    /// The longest attribute value or text content (in raw, still escaped bytes) accepted before
+    /// returning `ModinfoError::TooLarge` (default: no limit)
+    ///
+    /// This applies to every attribute, not just `value`, and is checked before the value is unescaped.
+    pub max_attribute_len: Option<usize>,
+    /// The largest document (in bytes) accepted before returning `ModinfoError::TooLarge` (default: no limit)
+    ///
+    /// This is checked before any parsing is done, so it only applies when the whole input is known,
+    /// i.e. not to `Modinfo::from_reader_config`.
+    pub max_total_len: Option<usize>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            trim_text: true,
+            accept_root: Vec::new(),
+            version_fallback: Some(Version::new(0, 0, 0)),
+            strict_semver: false,
+            required_fields: vec![
+                ModinfoField::Author,
+                ModinfoField::Description,
+                ModinfoField::Name,
+                ModinfoField::Version,
+            ],
+            max_attribute_len: None,
+            max_total_len: None,
+        }
+    }
+}
+
+/// Options which control how a Modinfo is written as XML
+///
+/// # Example
+///
+/// ```rust
+/// use modinfo::WriteOptions;
+///
+/// let options = WriteOptions { preserve_order: true, ..Default::default() };
+///
+/// assert!(options.preserve_order);
+/// ```
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+pub struct WriteOptions {
+    /// Write elements in the order they appeared in the parsed source (default: `false`)
+    ///
+    /// Fields which were not present in the source are appended in the canonical order.
+    pub preserve_order: bool,
+    /// Write the XML on a single line without indentation (default: `false`)
+    pub minified: bool,
+    /// The quotes used around attribute values (default: `QuoteStyle::Double`)
+    pub quote_style: QuoteStyle,
+    /// The line ending written between elements (default: `LineEnding::Lf`)
+    pub line_ending: LineEnding,
+    /// Leave out elements which have no value, instead of writing them with `value=""` (default: `false`)
+    ///
+    /// `Changelog`, `Priority` and `EnableMod` are always left out when they have no value.
+    pub omit_empty: bool,
+}
+
+/// The line ending written between elements, e.g. CRLF for files edited on Windows
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LineEnding {
+    /// Unix style `\n`
+    #[default]
+    Lf,
+    /// Windows style `\r\n`
+    CrLf,
+    /// Use the predominant line ending of the parsed source, or `\n` for a new Modinfo
+    Preserve,
+}
+
+/// The quote character written around attribute values, e.g. `value="SomeMod"` or `value='SomeMod'`
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum QuoteStyle {
+    /// Always use double quotes
+    #[default]
+    Double,
+    /// Always use single quotes
+    Single,
+    /// Use the quotes seen in the parsed source, or double quotes for a new Modinfo
+    Preserve,
+}
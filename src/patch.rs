@@ -0,0 +1,33 @@
+/// A sparse set of overrides to apply to a Modinfo with `Modinfo::apply_patch`
+///
+/// Every field is optional; `None` fields leave the Modinfo untouched. This makes it easy to
+/// bulk-edit many mods, e.g. moving everyone's website to a new domain.
+///
+/// # Example
+///
+/// ```rust
+/// use modinfo::ModinfoPatch;
+///
+/// let patch = ModinfoPatch { website: Some("https://example.org".to_owned()), ..Default::default() };
+///
+/// assert!(patch.author.is_none());
+/// ```
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq)]
+pub struct ModinfoPatch {
+    /// Overrides the author
+    pub author: Option<String>,
+    /// Overrides the changelog (V2 only)
+    pub changelog: Option<String>,
+    /// Overrides the compat value of the version, e.g. `A21`
+    pub compat: Option<String>,
+    /// Overrides the description
+    pub description: Option<String>,
+    /// Overrides the display name
+    pub display_name: Option<String>,
+    /// Overrides the internal name
+    pub name: Option<String>,
+    /// Overrides the modlet version, parsed leniently as by `try_set_value_for`
+    pub version: Option<String>,
+    /// Overrides the website
+    pub website: Option<String>,
+}
@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+/// Statistics about parsing a single ModInfo.xml file, returned by `parse_with_stats`
+///
+/// Useful for spotting pathological files when parsing large batches of mods.
+///
+/// # Example
+///
+/// ```rust
+/// use modinfo::ParseStats;
+///
+/// let stats = ParseStats::default();
+///
+/// assert_eq!(stats.element_count, 0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct ParseStats {
+    /// The size of the file contents parsed, in bytes
+    pub bytes_read: usize,
+    /// The number of child elements of the root, including localized display names
+    ///
+    /// An `<Authors>` container is not counted itself, only its `<Author>` entries.
+    pub element_count: usize,
+    /// The time taken to read and parse the file
+    pub duration: Duration,
+}
@@ -0,0 +1,169 @@
+use super::*;
+use std::cmp::Ordering;
+
+#[test]
+fn compat_version_from_str_test() {
+    assert_eq!(
+        CompatVersion::from_str("A21.2").unwrap(),
+        CompatVersion {
+            channel: CompatChannel::Alpha,
+            major: 21,
+            minor: Some(2),
+        }
+    );
+    assert_eq!(
+        CompatVersion::from_str("V1").unwrap(),
+        CompatVersion {
+            channel: CompatChannel::Stable,
+            major: 1,
+            minor: None,
+        }
+    );
+    assert!(matches!(
+        CompatVersion::from_str("B21"),
+        Err(ModinfoError::InvalidCompat(_))
+    ));
+    assert!(CompatVersion::from_str("A").is_err());
+    assert!(CompatVersion::from_str("A21.").is_err());
+    assert!(CompatVersion::from_str("A-1").is_err());
+}
+
+#[test]
+fn compat_version_display_test() {
+    assert_eq!(CompatVersion::from_str("A21.2").unwrap().to_string(), "A21.2");
+    assert_eq!(CompatVersion::from_str("V1").unwrap().to_string(), "V1");
+}
+
+#[test]
+fn compat_cmp_test() {
+    assert_eq!(compat_cmp("A21", "A20"), Some(Ordering::Greater));
+    assert_eq!(compat_cmp("A21.2", "A21"), Some(Ordering::Greater));
+    assert_eq!(compat_cmp("V1", "A99"), Some(Ordering::Greater));
+    assert_eq!(compat_cmp("A21.2", "A21.2"), Some(Ordering::Equal));
+    assert_eq!(compat_cmp("A21", "A21.0"), Some(Ordering::Equal));
+    assert_eq!(compat_cmp("A21.1", "A21"), Some(Ordering::Greater));
+    assert_eq!(compat_cmp("garbage", "A21"), None);
+    assert_eq!(compat_cmp("A21", "garbage"), None);
+}
+
+#[test]
+fn get_compat_list_test() {
+    let mut modinfo = Modinfo::new();
+    modinfo.set_value_for("compat", "A20,A21.2");
+
+    assert_eq!(
+        modinfo.get_compat_list(),
+        vec![
+            CompatVersion::from_str("A20").unwrap(),
+            CompatVersion::from_str("A21.2").unwrap(),
+        ]
+    );
+    assert_eq!(modinfo.get_value_for("compat"), Some(&Cow::from("A20,A21.2")));
+}
+
+#[test]
+fn set_compat_list_test() {
+    let mut modinfo = Modinfo::new();
+    modinfo.set_value_for("compat", "A20,A21.2");
+
+    let compat = modinfo.get_compat_list();
+    modinfo.set_compat_list(&compat);
+
+    assert_eq!(modinfo.get_value_for("compat"), Some(&Cow::from("A20,A21.2")));
+
+    modinfo.set_compat_list(&[]);
+    assert_eq!(modinfo.get_value_for("compat"), None);
+}
+
+#[test]
+fn compat_list_round_trip_test() {
+    let xml = fixtures::xml_string_v1().replace(r#"compat="A99""#, r#"compat="A20, A21.2""#);
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+
+    assert_eq!(modinfo.get_compat_list().len(), 2);
+    assert!(modinfo.to_string().contains(r#"compat="A20, A21.2""#));
+}
+
+#[test]
+fn get_version_with_compat_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v1()).unwrap();
+    let (version, compat) = modinfo.get_version_with_compat();
+
+    assert_eq!(version, &Version::new(1, 2, 3));
+    assert_eq!(compat, Some(CompatVersion::from_str("A99").unwrap()));
+
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v1_no_compat()).unwrap();
+
+    assert_eq!(modinfo.get_version_with_compat().1, None);
+}
+
+#[test]
+fn is_compatible_with_test() {
+    let game = CompatVersion::from_str("A21.2").unwrap();
+    let mut modinfo = Modinfo::default();
+
+    for compat in ["A21.2", "A21", "A20", "A19,A21"] {
+        modinfo.set_value_for("compat", compat);
+        assert!(modinfo.is_compatible_with(&game), "{} should support A21.2", compat);
+    }
+}
+
+#[test]
+fn is_compatible_with_mismatch_test() {
+    let game = CompatVersion::from_str("A21.2").unwrap();
+    let mut modinfo = Modinfo::default();
+
+    for compat in ["A22", "V1", "banana"] {
+        modinfo.set_value_for("compat", compat);
+        assert!(
+            !modinfo.is_compatible_with(&game),
+            "{} should not support A21.2",
+            compat
+        );
+    }
+}
+
+#[test]
+fn is_compatible_with_no_compat_test() {
+    let game = CompatVersion::from_str("V1.1").unwrap();
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2_no_compat()).unwrap();
+
+    assert!(modinfo.is_compatible_with(&game));
+}
+
+#[test]
+fn set_compat_alpha_test() {
+    let mut modinfo = Modinfo::default();
+    modinfo.set_compat_alpha(21);
+
+    assert_eq!(modinfo.compat(), Some("A21"));
+}
+
+#[test]
+fn set_compat_stable_test() {
+    let mut modinfo = Modinfo::default();
+    modinfo.set_compat_stable(1, Some(2));
+    assert_eq!(modinfo.compat(), Some("V1.2"));
+
+    modinfo.set_compat_stable(2, None);
+    assert_eq!(modinfo.compat(), Some("V2"));
+}
+
+#[test]
+fn compat_normalized_test() {
+    let mut modinfo = Modinfo::default();
+    assert_eq!(modinfo.compat_normalized(), None);
+
+    for (compat, expected) in [
+        ("a21", "A21"),
+        (" A21 ", "A21"),
+        ("v1.2,a21", "V1.2,A21"),
+        ("b 99", "B99"),
+    ] {
+        modinfo.set_value_for("compat", compat);
+        assert_eq!(modinfo.compat_normalized(), Some(expected.to_owned()));
+    }
+
+    modinfo.set_value_for("compat", "  ");
+    assert_eq!(modinfo.compat_normalized(), None);
+}
@@ -51,3 +51,164 @@ pub fn xml_string_v2_no_compat() -> String {
       "#
     .to_string()
 }
+
+pub fn xml_string_v2_with_comment() -> String {
+    r#"
+          <?xml version="1.0" encoding="UTF-8"?>
+          <xml>
+              <!--   keep   this   spacing   -->
+              <Name value="SomeInternalName" />
+              <DisplayName value="Official Mod Name" />
+              <Version value="2.3.4" compat="A99" />
+              <Description value="Mod to show format of ModInfo v2" />
+              <Author value="Name" />
+              <Website value="HP" />
+          </xml>
+      "#
+    .to_string()
+}
+
+pub fn xml_string_v2_localized() -> String {
+    r#"
+          <?xml version="1.0" encoding="UTF-8"?>
+          <xml>
+              <Name value="SomeInternalName" />
+              <DisplayName value="Official Mod Name" />
+              <DisplayName value="Ein Mod" lang="de" />
+              <DisplayName value="English Mod Name" lang="en" />
+              <Version value="2.3.4" compat="A99" />
+              <Description value="Mod to show format of ModInfo v2" />
+              <Author value="Name" />
+              <Website value="HP" />
+          </xml>
+      "#
+    .to_string()
+}
+
+pub fn xml_string_malformed() -> String {
+    r#"<?xml version="1.0" encoding="UTF-8"?>
+<xml>
+    <Name value="SomeInternalName" />
+    <Version value="2.3.4" />
+</ModInfo>
+"#
+    .to_string()
+}
+
+pub fn xml_string_custom_root() -> String {
+    r#"
+          <Mod>
+              <Name value="SomeInternalName" />
+              <DisplayName value="Official Mod Name" />
+              <Version value="2.3.4" />
+              <Description value="Mod wrapped in a custom root" />
+              <Author value="Name" />
+              <Website value="HP" />
+          </Mod>
+      "#
+    .to_string()
+}
+
+pub fn xml_string_v1_short_version() -> String {
+    r#"
+          <ModInfo>
+              <Name value="SomeInternalName" />
+              <Version value="1.2" />
+              <Description value="Mod to show format of ModInfo v1" />
+              <Author value="Name" />
+          </ModInfo>
+      "#
+    .to_string()
+}
+
+pub fn xml_string_v2_shuffled() -> String {
+    r#"
+          <?xml version="1.0" encoding="UTF-8"?>
+          <xml>
+              <Author value="Name" />
+              <Version value="2.3.4" compat="A99" />
+              <Name value="SomeInternalName" />
+              <Website value="HP" />
+              <Description value="Mod to show format of ModInfo v2" />
+              <DisplayName value="Official Mod Name" />
+          </xml>
+      "#
+    .to_string()
+}
+
+pub fn xml_string_v1_text_content() -> String {
+    r#"
+          <ModInfo>
+              <Name>SomeInternalName</Name>
+              <Version compat="A99">1.2.3</Version>
+              <Description>Mod to show format of ModInfo v1</Description>
+              <Author>Name</Author>
+          </ModInfo>
+      "#
+    .to_string()
+}
+
+pub fn xml_string_v2_changelog() -> String {
+    r#"
+          <?xml version="1.0" encoding="UTF-8"?>
+          <xml>
+              <Name value="SomeInternalName" />
+              <DisplayName value="Official Mod Name" />
+              <Version value="2.3.4" compat="A99" />
+              <Description value="Mod to show format of ModInfo v2" />
+              <Author value="Name" />
+              <Website value="HP" />
+              <Changelog value="Fixed the things" />
+          </xml>
+      "#
+    .to_string()
+}
+
+/// The exact output of `to_string` for `xml_string_v2`, see `Modinfo::to_string_exact`
+pub fn xml_string_v2_golden() -> String {
+    [
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        r#"<xml>"#,
+        r#"  <Name value="SomeInternalName"/>"#,
+        r#"  <DisplayName value="Official Mod Name"/>"#,
+        r#"  <Version value="2.3.4" compat="A99"/>"#,
+        r#"  <Description value="Mod to show format of ModInfo v2"/>"#,
+        r#"  <Author value="Name"/>"#,
+        r#"  <Website value="HP"/>"#,
+        r#"</xml>"#,
+    ]
+    .join("\n")
+}
+
+pub fn xml_string_v2_single_quotes() -> String {
+    r#"
+          <?xml version='1.0' encoding='UTF-8'?>
+          <xml>
+              <Name value='SomeInternalName' />
+              <DisplayName value='Official Mod Name' />
+              <Version value='2.3.4' compat='A99' />
+              <Description value='Mod to show format of ModInfo v2' />
+              <Author value='Name' />
+              <Website value='HP' />
+          </xml>
+      "#
+    .to_string()
+}
+
+pub fn xml_string_v2_authors() -> String {
+    r#"
+          <?xml version="1.0" encoding="UTF-8"?>
+          <xml>
+              <Name value="SomeInternalName" />
+              <DisplayName value="Official Mod Name" />
+              <Version value="2.3.4" compat="A99" />
+              <Description value="Mod to show format of ModInfo v2" />
+              <Authors>
+                  <Author value="First Author" />
+                  <Author value="Second Author" />
+              </Authors>
+              <Website value="HP" />
+          </xml>
+      "#
+    .to_string()
+}
@@ -8,3 +8,30 @@ mod modinfo_from_string_tests;
 
 #[cfg(test)]
 mod modinfo_to_string_tests;
+
+#[cfg(test)]
+mod parse_options_tests;
+
+#[cfg(test)]
+mod modinfo_fingerprint_tests;
+
+#[cfg(test)]
+mod compat_tests;
+
+#[cfg(test)]
+mod modinfo_version_tests;
+
+#[cfg(test)]
+mod parse_lenient_tests;
+
+#[cfg(test)]
+mod modinfo_semver_tests;
+
+#[cfg(test)]
+mod modinfo_value_tests;
+
+#[cfg(test)]
+mod modinfo_validation_tests;
+
+#[cfg(test)]
+mod modinfo_builder_tests;
@@ -0,0 +1,61 @@
+use super::*;
+
+#[test]
+fn builder_test() {
+    let modinfo = Modinfo::builder()
+        .name("SomeInternalName")
+        .display_name("Official Mod Name")
+        .version("2.3.4")
+        .compat("A99")
+        .description("Mod to show format of ModInfo v2")
+        .author("Name")
+        .website("HP")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        modinfo.canonical_xml(),
+        Modinfo::from_str(&fixtures::xml_string_v2()).unwrap().canonical_xml()
+    );
+}
+
+#[test]
+fn builder_optional_fields_test() {
+    let modinfo = Modinfo::builder()
+        .modinfo_version(ModinfoVersion::V1)
+        .name("SomeMod")
+        .version("1.2")
+        .description("Does things")
+        .author("Me")
+        .changelog("Fixed things")
+        .priority(5)
+        .enabled(false)
+        .build()
+        .unwrap();
+
+    assert!(modinfo.is_v1());
+    assert_eq!(modinfo.get_version(), &semver::Version::new(1, 2, 0));
+    assert_eq!(modinfo.get_changelog(), Some("Fixed things"));
+    assert_eq!(modinfo.get_priority(), Some(5));
+    assert_eq!(modinfo.is_enabled(), Some(false));
+    assert!(!modinfo.display_name_is_explicit());
+}
+
+#[test]
+fn builder_missing_fields_test() {
+    let builder = Modinfo::builder()
+        .name("SomeMod")
+        .description("Does things")
+        .author("Me");
+
+    assert!(matches!(builder.clone().build(), Err(ModinfoError::NoModinfoVersion)));
+    assert!(matches!(
+        builder.clone().version("banana").build(),
+        Err(ModinfoError::InvalidVersionString(_))
+    ));
+    assert!(matches!(
+        Modinfo::builder().version("1.0.0").build(),
+        Err(ModinfoError::NoModinfoAuthor)
+    ));
+    assert!(builder.version("1.0.0").build().is_ok());
+}
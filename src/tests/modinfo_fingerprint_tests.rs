@@ -0,0 +1,75 @@
+use super::*;
+
+#[test]
+fn fingerprint_ignores_path_test() {
+    let mut a = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let mut b = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+
+    a.set_file_path(PathBuf::from("Mods/A/ModInfo.xml"));
+    b.set_file_path(PathBuf::from("Mods/B/ModInfo.xml"));
+
+    assert_eq!(a.fingerprint(), b.fingerprint());
+}
+
+#[test]
+fn fingerprint_is_stable_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v1()).unwrap();
+
+    assert_eq!(modinfo.fingerprint(), modinfo.clone().fingerprint());
+    assert_eq!(Modinfo::new().fingerprint(), Modinfo::default().fingerprint());
+}
+
+#[test]
+fn fingerprint_changes_with_content_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+
+    let mut bumped = modinfo.clone();
+    bumped.bump_version_patch();
+
+    let mut v1 = modinfo.clone();
+    v1.set_modinfo_version(ModinfoVersion::V1);
+
+    assert_ne!(modinfo.fingerprint(), bumped.fingerprint());
+    assert_ne!(modinfo.fingerprint(), v1.fingerprint());
+}
+
+#[test]
+fn fingerprint_changes_with_authors_test() {
+    let xml = fixtures::xml_string_v2_authors();
+    let a = Modinfo::from_str(&xml).unwrap();
+    let b = Modinfo::from_str(&xml.replace(
+        r#"<Author value="First Author" />
+                  <Author value="Second Author" />"#,
+        r#"<Author value="First Author, Second Author" />"#,
+    ))
+    .unwrap();
+
+    assert_eq!(a.author(), b.author());
+    assert_ne!(a, b);
+    assert_ne!(a.fingerprint(), b.fingerprint());
+}
+
+#[test]
+fn stamp_build_hash_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    modinfo.add_version_build("previous");
+    modinfo.stamp_build_hash();
+
+    let stamped = modinfo.get_version().clone();
+
+    assert!(stamped.build.as_str().starts_with('h'));
+    assert_eq!(stamped.build.len(), 9);
+
+    for _ in 0..3 {
+        modinfo.stamp_build_hash();
+        assert_eq!(modinfo.get_version(), &stamped);
+    }
+
+    let mut other = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    other.stamp_build_hash();
+    assert_eq!(other.get_version(), &stamped);
+
+    modinfo.set_value_for("description", "Changed");
+    modinfo.stamp_build_hash();
+    assert_ne!(modinfo.get_version(), &stamped);
+}
@@ -19,10 +19,7 @@ fn from_str_v1_test() {
     );
     assert_eq!(
         result.version,
-        ModinfoValueVersion {
-            value: version,
-            compat: Some("A99".to_owned().into()),
-        }
+        ModinfoValueVersion::new(version, Some("A99".to_owned().into()))
     );
     assert_eq!(
         result.description,
@@ -56,13 +53,7 @@ fn from_str_v1_no_compat_test() {
             value: Some("Some Internal Name".to_owned().into())
         }
     );
-    assert_eq!(
-        result.version,
-        ModinfoValueVersion {
-            value: version,
-            compat: None
-        }
-    );
+    assert_eq!(result.version, ModinfoValueVersion::new(version, None));
     assert_eq!(
         result.description,
         ModinfoValue {
@@ -97,10 +88,7 @@ fn from_str_v2_test() {
     );
     assert_eq!(
         result.version,
-        ModinfoValueVersion {
-            value: version,
-            compat: Some("A99".to_owned().into())
-        }
+        ModinfoValueVersion::new(version, Some("A99".to_owned().into()))
     );
     assert_eq!(
         result.description,
@@ -139,13 +127,7 @@ fn from_str_v2_no_compat_test() {
             value: Some("Official Mod Name".to_owned().into())
         }
     );
-    assert_eq!(
-        result.version,
-        ModinfoValueVersion {
-            value: version,
-            compat: None
-        }
-    );
+    assert_eq!(result.version, ModinfoValueVersion::new(version, None));
     assert_eq!(
         result.description,
         ModinfoValue {
@@ -165,3 +147,279 @@ fn from_str_v2_no_compat_test() {
         }
     );
 }
+
+#[test]
+fn from_str_v2_localized_test() {
+    let result = Modinfo::from_str(&fixtures::xml_string_v2_localized()).unwrap();
+
+    assert_eq!(
+        result.display_name,
+        ModinfoValue {
+            value: Some("Official Mod Name".to_owned().into())
+        }
+    );
+    assert_eq!(result.get_display_name_localized("en"), Some("English Mod Name"));
+    assert_eq!(result.get_display_name_localized("de"), Some("Ein Mod"));
+    assert_eq!(result.get_display_name_localized("fr"), Some("Official Mod Name"));
+}
+
+#[test]
+fn from_str_malformed_test() {
+    let xml = fixtures::xml_string_malformed();
+    let defect = xml.find("</ModInfo>").unwrap();
+
+    match Modinfo::from_str(&xml) {
+        Err(ModinfoError::XMLErrorAt { position, .. }) => {
+            assert!(position > 0);
+            assert!(position >= defect && position <= defect + "</ModInfo>".len());
+        }
+        other => panic!("expected XMLErrorAt, got {:?}", other),
+    }
+}
+
+#[test]
+fn parse_all_test() {
+    let results = parse_all(vec![
+        fixtures::xml_string_v1(),
+        fixtures::xml_string_malformed(),
+        fixtures::xml_string_v2(),
+    ]);
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert!(matches!(results[1], Err(ModinfoError::XMLErrorAt { .. })));
+    assert!(results[2].is_ok());
+    assert_eq!(results[2].as_ref().unwrap().meta.version, ModinfoVersion::V2);
+}
+
+#[test]
+fn from_str_v1_text_content_test() {
+    let result = Modinfo::from_str(&fixtures::xml_string_v1_text_content()).unwrap();
+
+    assert_eq!(result, Modinfo::from_str(&fixtures::xml_string_v1()).unwrap());
+    assert_eq!(result.meta.version, ModinfoVersion::V1);
+    assert_eq!(result.name(), Some("SomeInternalName"));
+    assert_eq!(result.author(), Some("Name"));
+    assert_eq!(result.get_version(), &Version::new(1, 2, 3));
+    assert_eq!(result.compat(), Some("A99"));
+}
+
+#[test]
+fn from_str_raw_attributes_test() {
+    let xml = fixtures::xml_string_v1().replace(r#"compat="A99""#, r#"compat="A99" foo="bar""#);
+    let result = Modinfo::from_str(&xml).unwrap();
+    let attributes = result.raw_attributes("Version").unwrap();
+
+    assert_eq!(attributes.get("foo"), Some(&"bar".to_owned()));
+    assert_eq!(attributes.get("value"), Some(&"1.2.3".to_owned()));
+    assert_eq!(attributes.get("compat"), Some(&"A99".to_owned()));
+    assert_eq!(result.raw_attributes("Website"), None);
+}
+
+#[test]
+fn from_str_raw_attributes_localized_test() {
+    let result = Modinfo::from_str(&fixtures::xml_string_v2_localized()).unwrap();
+    let attributes = result.raw_attributes("DisplayName").unwrap();
+
+    assert_eq!(attributes.get("value"), Some(&"Official Mod Name".to_owned()));
+    assert_eq!(attributes.get("lang"), None);
+}
+
+#[test]
+fn from_str_raw_attributes_order_test() {
+    let xml = r#"<xml xmlns:xsi="x" b="2" a="1"><Version zeta="z" compat="A99" Alpha="a" value="1.2.3" mid="m" /><Name mid="m" value="SomeMod" alpha="a" /><DisplayName value="Mon Mod" lang="fr" /><DisplayName value="Mein Mod" lang="de" /></xml>"#;
+    let options = WriteOptions {
+        preserve_order: true,
+        ..Default::default()
+    };
+    let expected = [
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        r#"<xml xmlns:xsi="x" b="2" a="1">"#,
+        r#"  <Version value="1.2.3" compat="A99"/>"#,
+        r#"  <Name value="SomeMod"/>"#,
+        r#"  <DisplayName value="Some Mod"/>"#,
+        r#"  <DisplayName value="Mein Mod" lang="de"/>"#,
+        r#"  <DisplayName value="Mon Mod" lang="fr"/>"#,
+        r#"  <Description value=""/>"#,
+        r#"  <Author value=""/>"#,
+        r#"  <Website value=""/>"#,
+        r#"</xml>"#,
+    ]
+    .join("\n");
+
+    for _ in 0..10 {
+        let result = Modinfo::from_str(xml).unwrap();
+
+        assert_eq!(result.to_string_with_options(&options), expected);
+    }
+
+    // attributes are written in a fixed order, whatever order they were given in
+    let result = Modinfo::from_str(r#"<xml><Version compat="A21" value="1.0.0"/></xml>"#).unwrap();
+    assert!(result
+        .to_string_with_options(&options)
+        .contains(r#"<Version value="1.0.0" compat="A21"/>"#));
+    assert_eq!(
+        result.raw_attributes("Version").unwrap().keys().collect::<Vec<_>>(),
+        vec!["compat", "value"]
+    );
+}
+
+#[test]
+fn from_reader_config_test() {
+    let xml = fixtures::xml_string_v2();
+    let mut reader = Reader::from_str(&xml);
+    reader.trim_text(true);
+
+    let result = Modinfo::from_reader_config(reader).unwrap();
+
+    assert_eq!(result, Modinfo::from_str(&xml).unwrap());
+}
+
+#[test]
+fn from_reader_config_buffered_test() {
+    let xml = fixtures::xml_string_v1_text_content();
+    let mut reader = Reader::from_reader(std::io::BufReader::new(xml.as_bytes()));
+    reader.trim_text(true);
+
+    let result = Modinfo::from_reader_config(reader).unwrap();
+
+    assert_eq!(result.name(), Some("SomeInternalName"));
+    assert_eq!(result.get_version(), &Version::new(1, 2, 3));
+}
+
+#[test]
+fn parse_multi_test() {
+    let second = fixtures::xml_string_v2().replace("SomeInternalName", "OtherInternalName");
+    let xml = format!("{}\n\n{}\n   \n", fixtures::xml_string_v2(), second);
+
+    let result = parse_multi(&xml).unwrap();
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].name(), Some("SomeInternalName"));
+    assert_eq!(result[1].name(), Some("OtherInternalName"));
+    assert!(result.iter().all(|modinfo| modinfo.is_v2()));
+}
+
+#[test]
+fn parse_multi_mixed_versions_test() {
+    let xml = format!("{}{}", fixtures::xml_string_v1(), fixtures::xml_string_v2());
+
+    let result = parse_multi(&xml).unwrap();
+
+    assert_eq!(result.len(), 2);
+    assert!(result[0].is_v1());
+    assert!(result[1].is_v2());
+    assert_eq!(result[0], Modinfo::from_str(&fixtures::xml_string_v1()).unwrap());
+}
+
+#[test]
+fn tag_counts_test() {
+    let xml = fixtures::xml_string_v2().replace(
+        r#"<Author value="Name" />"#,
+        r#"<Author value="Name" /><Author value="Other Name" />"#,
+    );
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let counts = modinfo.tag_counts();
+
+    assert_eq!(counts["Author"], 2);
+    assert_eq!(counts["Name"], 1);
+    assert_eq!(counts.get("Changelog"), None);
+}
+
+#[test]
+fn from_str_leading_junk_test() {
+    let xml = format!("\n\n<!-- exported by SomeTool -->\n{}", fixtures::xml_string_v1());
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+
+    assert!(modinfo.is_v1());
+    assert_eq!(modinfo.name(), Some("SomeInternalName"));
+}
+
+#[test]
+fn from_str_leading_bom_and_text_test() {
+    let xml = format!("\u{feff}stray text\n{}", fixtures::xml_string_v1());
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+
+    assert!(modinfo.is_v1());
+    assert_eq!(modinfo, Modinfo::from_str(&fixtures::xml_string_v1()).unwrap());
+}
+
+#[test]
+fn from_static_test() {
+    static TEMPLATE: &str = r#"
+        <?xml version="1.0" encoding="UTF-8"?>
+        <xml>
+            <Name value="SomeInternalName" />
+            <Version value="2.3.4" compat="A99" />
+            <Description value="Fish &amp; Chips" />
+            <Author value="Name" />
+        </xml>
+    "#;
+    let modinfo = Modinfo::from_static(TEMPLATE).unwrap();
+
+    assert_eq!(modinfo.name(), Some("SomeInternalName"));
+    assert!(matches!(modinfo.name.value, Some(Cow::Borrowed(_))));
+    assert!(matches!(modinfo.author.value, Some(Cow::Borrowed(_))));
+    assert!(matches!(modinfo.version.compat, Some(Cow::Borrowed(_))));
+    // unescaped values don't appear verbatim in the source, so they stay owned
+    assert_eq!(modinfo.description(), Some("Fish & Chips"));
+    assert!(matches!(modinfo.description.value, Some(Cow::Owned(_))));
+    assert_eq!(modinfo, Modinfo::from_str(TEMPLATE).unwrap());
+}
+
+#[test]
+fn from_static_borrows_from_own_element_test() {
+    static TEMPLATE: &str = r#"<xml><Name value="Same" /><Author value="Same" /></xml>"#;
+    let modinfo = Modinfo::from_static(TEMPLATE).unwrap();
+
+    let author_start = TEMPLATE.find(r#"<Author value=""#).unwrap() + r#"<Author value=""#.len();
+    assert!(matches!(modinfo.author.value, Some(Cow::Borrowed(_))));
+    assert_eq!(modinfo.author().unwrap().as_ptr(), TEMPLATE[author_start..].as_ptr());
+}
+
+#[test]
+fn from_str_single_quotes_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2_single_quotes()).unwrap();
+
+    assert_eq!(modinfo.name(), Some("SomeInternalName"));
+    assert_eq!(modinfo.author(), Some("Name"));
+    assert_eq!(modinfo.get_version(), &semver::Version::new(2, 3, 4));
+    assert_eq!(modinfo.compat(), Some("A99"));
+    assert_eq!(
+        modinfo.canonical_xml(),
+        Modinfo::from_str(&fixtures::xml_string_v2()).unwrap().canonical_xml()
+    );
+}
+
+#[test]
+fn from_str_single_quotes_containing_double_quotes_test() {
+    let modinfo = Modinfo::from_str(r#"<xml><Description value='Say "hi"' /></xml>"#).unwrap();
+
+    assert_eq!(modinfo.description(), Some(r#"Say "hi""#));
+}
+
+#[test]
+fn parse_str_test() {
+    let modinfo = parse_str(&fixtures::xml_string_v2()).unwrap();
+
+    assert_eq!(modinfo, Modinfo::from_str(&fixtures::xml_string_v2()).unwrap());
+}
+
+#[test]
+fn parse_str_missing_author_test() {
+    let xml = fixtures::xml_string_v2().replace(r#"<Author value="Name" />"#, "");
+
+    assert!(Modinfo::from_str(&xml).is_ok());
+    assert!(matches!(parse_str(&xml), Err(ModinfoError::NoModinfoAuthor)));
+}
+
+#[test]
+fn from_str_equality_ignores_source_formatting_test() {
+    let plain = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let commented = Modinfo::from_str(&fixtures::xml_string_v2_with_comment()).unwrap();
+    let shuffled = Modinfo::from_str(&fixtures::xml_string_v2_shuffled()).unwrap();
+
+    assert_eq!(commented, plain);
+    assert_eq!(shuffled, plain);
+    assert_eq!(shuffled.cmp(&plain), std::cmp::Ordering::Equal);
+}
@@ -0,0 +1,219 @@
+use super::*;
+use std::cmp::Ordering;
+
+fn modinfo_with_version(version: &str) -> Modinfo {
+    let mut modinfo = Modinfo::new();
+    modinfo.set_version(version.to_owned());
+    modinfo
+}
+
+#[test]
+fn version_precedence_pre_release_test() {
+    let rc = modinfo_with_version("1.0.0-rc.1");
+    let rc2 = modinfo_with_version("1.0.0-rc.2");
+    let release = modinfo_with_version("1.0.0");
+
+    assert_eq!(rc.version_precedence(&release), Ordering::Less);
+    assert_eq!(release.version_precedence(&rc), Ordering::Greater);
+    assert_eq!(rc.version_precedence(&rc2), Ordering::Less);
+}
+
+#[test]
+fn version_precedence_ignores_build_test() {
+    let a = modinfo_with_version("1.0.0+a");
+    let b = modinfo_with_version("1.0.0+b");
+
+    assert_eq!(a.version_precedence(&b), Ordering::Equal);
+    assert_ne!(a.get_version().cmp(b.get_version()), Ordering::Equal);
+}
+
+#[test]
+fn version_pre_and_build_test() {
+    let mut modinfo = modinfo_with_version("1.2.3");
+
+    assert_eq!(modinfo.get_version_pre(), None);
+    assert_eq!(modinfo.get_version_build(), None);
+
+    modinfo.add_version_pre("rc.1");
+    modinfo.add_version_build("20240101");
+
+    assert_eq!(modinfo.get_version_pre(), Some("rc.1"));
+    assert_eq!(modinfo.get_version_build(), Some("20240101"));
+
+    modinfo.clear_version_pre();
+    assert_eq!(modinfo.get_version_pre(), None);
+    assert_eq!(modinfo.get_version_build(), Some("20240101"));
+
+    modinfo.clear_version_build();
+    assert_eq!(modinfo.get_version_build(), None);
+    assert_eq!(modinfo.get_version(), &Version::new(1, 2, 3));
+}
+
+#[test]
+fn is_upgrade_over_test() {
+    let mut installed = modinfo_with_version("1.2.3");
+    installed.set_value_for("name", "SomeMod");
+
+    let mut higher = installed.clone();
+    higher.set_version("1.2.4".to_owned());
+
+    let mut other = higher.clone();
+    other.set_value_for("name", "OtherMod");
+
+    assert!(higher.is_upgrade_over(&installed));
+    assert!(!installed.is_upgrade_over(&higher));
+    assert!(!installed.clone().is_upgrade_over(&installed));
+    assert!(!other.is_upgrade_over(&installed));
+}
+
+#[test]
+fn is_upgrade_over_unnamed_test() {
+    let installed = modinfo_with_version("1.0.0");
+    let higher = modinfo_with_version("2.0.0");
+
+    assert!(!higher.is_upgrade_over(&installed));
+}
+
+#[test]
+fn version_v_prefix_test() {
+    let xml = fixtures::xml_string_v2().replace(r#"value="2.3.4""#, r#"value="v2.3.4""#);
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+
+    assert_eq!(modinfo.get_version(), &Version::new(2, 3, 4));
+    assert!(modinfo.to_string().contains(r#"<Version value="2.3.4" compat="A99"/>"#));
+}
+
+#[test]
+fn version_parts_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v1()).unwrap();
+
+    assert_eq!(modinfo.version_major(), 1);
+    assert_eq!(modinfo.version_minor(), 2);
+    assert_eq!(modinfo.version_patch(), 3);
+}
+
+#[test]
+fn bump_with_custom_strategy_test() {
+    struct DoublePatch;
+
+    impl BumpStrategy for DoublePatch {
+        fn bump(&self, version: &mut Version) {
+            version.patch += 2;
+        }
+    }
+
+    let mut modinfo = modinfo_with_version("1.2.3");
+    modinfo.bump_with(DoublePatch);
+
+    assert_eq!(modinfo.get_version(), &Version::new(1, 2, 5));
+}
+
+#[test]
+fn bump_with_builtin_strategies_test() {
+    let mut modinfo = modinfo_with_version("1.2.3-rc.1");
+    modinfo.bump_with(SemverPatch);
+    assert_eq!(modinfo.get_version(), &Version::new(1, 2, 4));
+
+    modinfo.bump_with(SemverMinor);
+    assert_eq!(modinfo.get_version(), &Version::new(1, 3, 0));
+
+    modinfo.bump_with(SemverMajor);
+    assert_eq!(modinfo.get_version(), &Version::new(2, 0, 0));
+
+    modinfo.bump_with(CalVer { year: 2024, month: 3 });
+    assert_eq!(modinfo.get_version(), &Version::new(2024, 3, 0));
+}
+
+#[test]
+fn to_release_version_test() {
+    let mut modinfo = modinfo_with_version("1.2.3-rc.1+abc");
+    let release = modinfo.release_version();
+    modinfo.to_release_version();
+
+    assert_eq!(modinfo.get_version(), &Version::new(1, 2, 3));
+    assert_eq!(modinfo.get_version(), &release);
+    assert_eq!(modinfo.get_version_pre(), None);
+    assert_eq!(modinfo.get_version_build(), None);
+}
+
+#[test]
+fn version_display_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v1()).unwrap();
+
+    assert_eq!(modinfo.version_display_human(), "1.2.3 (A99)");
+    assert_eq!(modinfo.version_display_xml(), "1.2.3");
+}
+
+#[test]
+fn version_display_no_compat_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v1_no_compat()).unwrap();
+
+    assert_eq!(modinfo.version_display_human(), "1.2.3");
+    assert_eq!(modinfo.version_display_xml(), "1.2.3");
+}
+
+#[test]
+fn same_release_ignores_build_test() {
+    let mut a = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let mut b = a.clone();
+    a.add_version_build("build.1");
+    b.add_version_pre("rc.1");
+    b.set_file_path(PathBuf::from("Mods/Other/ModInfo.xml"));
+
+    assert!(a.same_release(&b));
+}
+
+#[test]
+fn same_release_authors_differ_test() {
+    let xml = fixtures::xml_string_v2_authors();
+    let a = Modinfo::from_str(&xml).unwrap();
+    let b = Modinfo::from_str(&xml.replace(r#"<Author value="Second Author" />"#, "")).unwrap();
+    let c = Modinfo::from_str(&xml.replace(
+        r#"<Author value="First Author" />
+                  <Author value="Second Author" />"#,
+        r#"<Author value="First Author, Second Author" />"#,
+    ))
+    .unwrap();
+
+    assert!(a.same_release(&a.clone()));
+    assert!(!a.same_release(&b));
+    assert!(!a.same_release(&c));
+}
+
+#[test]
+fn same_release_patch_differs_test() {
+    let a = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let mut b = a.clone();
+    b.bump_version_patch();
+
+    assert!(!a.same_release(&b));
+}
+
+#[test]
+fn bump_pre_test() {
+    let mut modinfo = Modinfo::default();
+
+    for (version, expected) in [
+        ("1.2.3-rc.1", "1.2.3-rc.2"),
+        ("1.2.3", "1.2.3-rc.1"),
+        ("1.2.3-beta", "1.2.3-beta.1"),
+        ("1.2.3-1", "1.2.3-2"),
+        ("1.2.3-alpha.beta", "1.2.3-alpha.beta.1"),
+        ("1.0.0-rc.18446744073709551615", "1.0.0-rc.18446744073709551615.1"),
+        ("1.0.0-18446744073709551615", "1.0.0-18446744073709551615.1"),
+        ("1.2.3-rc.9+build", "1.2.3-rc.10"),
+    ] {
+        modinfo.set_version(version.to_owned());
+        modinfo.bump_pre();
+        assert_eq!(modinfo.get_version().to_string(), expected);
+    }
+}
+
+#[test]
+fn version_satisfies_test() {
+    let mut modinfo = Modinfo::default();
+    modinfo.set_version("1.2.3".to_owned());
+
+    assert!(modinfo.version_satisfies(&semver::VersionReq::parse(">=1.0.0").unwrap()));
+    assert!(!modinfo.version_satisfies(&semver::VersionReq::parse(">=2.0.0").unwrap()));
+}
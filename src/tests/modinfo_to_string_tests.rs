@@ -35,3 +35,389 @@ fn to_string_v2_no_compat_test() {
 
     assert_eq!(strip_ws(&result), strip_ws(&xml));
 }
+
+#[test]
+fn to_string_v2_localized_test() {
+    let xml = fixtures::xml_string_v2_localized();
+    let result = Modinfo::from_str(&xml).unwrap().to_string();
+
+    assert_eq!(strip_ws(&result), strip_ws(&xml));
+}
+
+#[test]
+fn to_string_normalizes_version_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v1_short_version()).unwrap();
+    let result = modinfo.to_string();
+
+    assert!(!modinfo.version_is_normalized());
+    assert!(result.contains(r#"<Version value="1.2.0"/>"#));
+    assert!(!result.contains(r#"value="1.2""#));
+    assert!(Modinfo::from_str(&result).unwrap().version_is_normalized());
+}
+
+#[test]
+fn set_version_normalizes_test() {
+    let mut modinfo = Modinfo::new();
+    modinfo.set_version("3".to_owned());
+
+    assert!(modinfo.version_is_normalized());
+    assert!(modinfo.to_string().contains(r#"<Version value="3.0.0"/>"#));
+}
+
+#[test]
+fn to_string_preserve_order_test() {
+    let xml = fixtures::xml_string_v2_shuffled();
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let options = WriteOptions {
+        preserve_order: true,
+        ..Default::default()
+    };
+
+    assert_eq!(strip_ws(&modinfo.to_string_with_options(&options)), strip_ws(&xml));
+}
+
+#[test]
+fn to_string_canonical_order_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2_shuffled()).unwrap();
+
+    assert_eq!(strip_ws(&modinfo.to_string()), strip_ws(&fixtures::xml_string_v2()));
+}
+
+#[test]
+fn to_string_escapes_values_test() {
+    let description = r#"Fixes <stuff> & "things""#;
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    modinfo.set_value_for("description", description);
+
+    let xml = modinfo.to_string();
+
+    assert!(xml.contains(r#"<Description value="Fixes &lt;stuff&gt; &amp; &quot;things&quot;"/>"#));
+
+    let result = Modinfo::from_str(&xml).unwrap();
+
+    assert_eq!(result.description(), Some(description));
+    assert_eq!(result, modinfo);
+}
+
+#[test]
+fn to_string_v2_changelog_test() {
+    let xml = fixtures::xml_string_v2_changelog();
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+
+    assert_eq!(modinfo.get_changelog(), Some("Fixed the things"));
+    assert_eq!(strip_ws(&modinfo.to_string()), strip_ws(&xml));
+}
+
+#[test]
+fn to_string_v1_drops_changelog_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2_changelog()).unwrap();
+    modinfo.set_modinfo_version(ModinfoVersion::V1);
+
+    assert!(!modinfo.to_string().contains("Changelog"));
+}
+
+#[test]
+fn to_string_v2_root_attributes_test() {
+    let xml = fixtures::xml_string_v2().replace(
+        "<xml>",
+        r#"<xml xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">"#,
+    );
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let output = modinfo.to_string();
+
+    assert!(modinfo.is_v2());
+    assert!(output.contains(r#"<xml xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">"#));
+    assert_eq!(Modinfo::from_str(&output).unwrap(), modinfo);
+}
+
+#[test]
+fn serialize_checked_v1_website_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v1()).unwrap();
+    modinfo.set_value_for("website", "https://example.org");
+
+    let warnings = modinfo.serialize_checked().unwrap_err();
+    let output = modinfo.to_string();
+
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].contains("website"));
+    assert!(!output.contains("Website"));
+    assert!(Modinfo::from_str(&output).unwrap().is_v1());
+}
+
+#[test]
+fn to_string_v2_declaration_test() {
+    let xml = fixtures::xml_string_v2().replace(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        r#"<?xml version="1.0" encoding="ISO-8859-1" standalone="yes"?>"#,
+    );
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let output = modinfo.to_string();
+
+    assert!(output.starts_with(r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#));
+    assert_eq!(Modinfo::from_str(&output).unwrap(), modinfo);
+}
+
+#[test]
+fn to_string_declaration_without_encoding_test() {
+    let xml =
+        fixtures::xml_string_v2().replace(r#"<?xml version="1.0" encoding="UTF-8"?>"#, r#"<?xml version="1.1"?>"#);
+    let output = Modinfo::from_str(&xml).unwrap().to_string();
+
+    assert!(output.starts_with(r#"<?xml version="1.1" encoding="UTF-8"?>"#));
+}
+
+#[test]
+fn to_string_minified_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let options = WriteOptions {
+        minified: true,
+        ..Default::default()
+    };
+    let output = modinfo.to_string_with_options(&options);
+
+    assert!(!output.contains('\n'));
+    assert_eq!(Modinfo::from_str(&output).unwrap(), modinfo);
+}
+
+#[test]
+fn to_string_prolog_test() {
+    let xml = fixtures::xml_string_v2().replace(
+        "<xml>",
+        "<?xml-stylesheet type=\"text/xsl\" href=\"modinfo.xsl\"?>\n<!DOCTYPE xml>\n<xml>",
+    );
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let output = modinfo.to_string();
+
+    assert!(output.contains(r#"<?xml-stylesheet type="text/xsl" href="modinfo.xsl"?>"#));
+    assert!(output.contains("<!DOCTYPE xml>"));
+    assert!(output.find("<?xml-stylesheet").unwrap() < output.find("<xml").unwrap());
+    assert_eq!(Modinfo::from_str(&output).unwrap(), modinfo);
+}
+
+#[test]
+fn to_string_trailing_pi_test() {
+    let xml = format!(
+        "{}\n<?trailing-pi done?>\n<!DOCTYPE xml>",
+        fixtures::xml_string_v2().trim_end()
+    );
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let output = modinfo.to_string();
+
+    assert!(output.ends_with("</xml>\n<?trailing-pi done?>"));
+    assert!(!output.contains("<!DOCTYPE"));
+    assert!(output.find("<xml").unwrap() < output.find("<?trailing-pi").unwrap());
+    assert_eq!(Modinfo::from_str(&output).unwrap().to_string(), output);
+}
+
+#[test]
+fn canonical_xml_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let pretty = Modinfo::from_str(&modinfo.to_string()).unwrap();
+    let minified = Modinfo::from_str(&modinfo.to_string_with_options(&WriteOptions {
+        minified: true,
+        ..Default::default()
+    }))
+    .unwrap();
+
+    assert_eq!(pretty.canonical_xml(), minified.canonical_xml());
+    assert_eq!(
+        Modinfo::from_str(&fixtures::xml_string_v2_shuffled())
+            .unwrap()
+            .canonical_xml(),
+        modinfo.canonical_xml()
+    );
+}
+
+#[test]
+fn to_string_cdata_description_test() {
+    let xml = fixtures::xml_string_v2().replace(
+        r#"<Description value="Mod to show format of ModInfo v2" />"#,
+        r#"<Description><![CDATA[<b>bold</b> & "quoted"]]></Description>"#,
+    );
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let output = modinfo.to_string();
+
+    assert_eq!(modinfo.description(), Some(r#"<b>bold</b> & "quoted""#));
+    assert!(output.contains(r#"<![CDATA[<b>bold</b> & "quoted"]]>"#));
+    assert_eq!(Modinfo::from_str(&output).unwrap(), modinfo);
+}
+
+#[test]
+fn to_string_exact_golden_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+
+    assert_eq!(
+        modinfo.to_string_exact(&WriteOptions::default()),
+        fixtures::xml_string_v2_golden()
+    );
+}
+
+#[test]
+fn to_string_exact_minified_golden_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let options = WriteOptions {
+        minified: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        modinfo.to_string_exact(&options),
+        fixtures::xml_string_v2_golden().replace("\n", "").replace("  <", "<")
+    );
+}
+
+#[test]
+fn to_json_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    modinfo.set_value_for("description", "Say \"hi\"\n\\o/");
+    let json = modinfo.to_json();
+
+    for key in [
+        "name",
+        "display_name",
+        "version",
+        "compat",
+        "description",
+        "author",
+        "website",
+        "modinfo_version",
+    ] {
+        assert!(json.contains(&format!("\"{}\":", key)), "{}", key);
+    }
+    assert!(json.contains(r#""description":"Say \"hi\"\n\\o/""#));
+    assert!(json.contains(r#""version":"2.3.4","compat":"A99""#));
+    assert!(json.ends_with(r#""modinfo_version":"V2"}"#));
+}
+
+#[test]
+fn to_string_quote_style_preserve_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2_single_quotes()).unwrap();
+    let options = WriteOptions {
+        quote_style: QuoteStyle::Preserve,
+        ..Default::default()
+    };
+    let output = modinfo.to_string_with_options(&options);
+
+    assert!(output.starts_with("<?xml version='1.0' encoding='UTF-8'?>"));
+    assert!(output.contains("<Version value='2.3.4' compat='A99'/>"));
+    assert!(!output.contains('"'));
+    assert_eq!(Modinfo::from_str(&output).unwrap(), modinfo);
+    assert!(modinfo.to_string().contains(r#"<Name value="SomeInternalName"/>"#));
+    assert_eq!(modinfo, Modinfo::from_str(&fixtures::xml_string_v2()).unwrap());
+}
+
+#[test]
+fn to_string_quote_style_single_escapes_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    modinfo.set_value_for("description", "It's a mod");
+    let options = WriteOptions {
+        quote_style: QuoteStyle::Single,
+        ..Default::default()
+    };
+    let output = modinfo.to_string_with_options(&options);
+
+    assert!(output.contains("<Description value='It&apos;s a mod'/>"));
+    assert_eq!(Modinfo::from_str(&output).unwrap().description(), Some("It's a mod"));
+}
+
+#[test]
+fn to_string_authors_round_trip_test() {
+    let attribute_form = fixtures::xml_string_v2_authors();
+    let text_form = attribute_form
+        .replace(r#"<Author value="First Author" />"#, "<Author>First Author</Author>")
+        .replace(r#"<Author value="Second Author" />"#, "<Author>Second Author</Author>");
+
+    for xml in [attribute_form, text_form] {
+        let modinfo = Modinfo::from_str(&xml).unwrap();
+
+        assert_eq!(modinfo.author(), Some("First Author, Second Author"));
+        assert_eq!(modinfo.authors(), vec!["First Author", "Second Author"]);
+        assert!(modinfo.validate().is_ok());
+        assert!(modinfo.validate_schema(ModinfoVersion::V2).is_ok());
+
+        let output = modinfo.to_string();
+        assert!(output.contains(
+            "  <Authors>\n    <Author value=\"First Author\"/>\n    <Author value=\"Second Author\"/>\n  </Authors>\n"
+        ));
+        assert_eq!(Modinfo::from_str(&output).unwrap(), modinfo);
+    }
+}
+
+#[test]
+fn to_string_single_author_is_flat_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2_authors()).unwrap();
+    modinfo.set_value_for("author", "Only Author");
+
+    assert_eq!(modinfo.authors(), vec!["Only Author"]);
+    assert!(modinfo.to_string().contains(r#"<Author value="Only Author"/>"#));
+    assert!(!modinfo.to_string().contains("<Authors>"));
+}
+
+#[test]
+fn to_string_invalid_version_fallback_test() {
+    let modinfo = Modinfo::from_str(r#"<xml><Name value="SomeMod" /><Version value="banana" /></xml>"#).unwrap();
+    let output = modinfo.to_string();
+
+    assert!(output.contains(r#"<Version value="0.0.0"/>"#));
+    assert!(!output.contains('+'));
+    assert!(modinfo.meta.version_parse_error.as_deref().unwrap().contains('b'));
+    assert!(Modinfo::from_str(&output).unwrap().meta.version_parse_error.is_none());
+}
+
+#[test]
+fn to_string_line_ending_preserve_test() {
+    let xml = fixtures::xml_string_v2().replace('\n', "\r\n");
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let options = WriteOptions {
+        line_ending: LineEnding::Preserve,
+        ..Default::default()
+    };
+    let output = modinfo.to_string_with_options(&options);
+
+    assert_eq!(output.matches("\r\n").count(), output.matches('\n').count());
+    assert!(output.contains("?>\r\n<xml>\r\n  <Name"));
+    assert!(!modinfo.to_string().contains('\r'));
+    assert_eq!(modinfo, Modinfo::from_str(&fixtures::xml_string_v2()).unwrap());
+}
+
+#[test]
+fn to_string_line_ending_crlf_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let preserved = WriteOptions {
+        line_ending: LineEnding::Preserve,
+        ..Default::default()
+    };
+    let crlf = WriteOptions {
+        line_ending: LineEnding::CrLf,
+        ..Default::default()
+    };
+
+    assert!(!modinfo.to_string_with_options(&preserved).contains('\r'));
+    assert_eq!(
+        modinfo.to_string_with_options(&crlf),
+        modinfo.to_string().replace('\n', "\r\n")
+    );
+}
+
+#[test]
+fn to_string_line_ending_keeps_value_newlines_test() {
+    let xml = fixtures::xml_string_v2().replace(
+        r#"<Description value="Mod to show format of ModInfo v2" />"#,
+        "<Description><![CDATA[x\ny]]></Description>",
+    );
+    let mut modinfo = Modinfo::from_str(&xml).unwrap();
+    modinfo.set_value_for("changelog", "line1\nline2");
+    let options = WriteOptions {
+        line_ending: LineEnding::CrLf,
+        ..Default::default()
+    };
+    let output = modinfo.to_string_with_options(&options);
+
+    assert!(output.contains("<Description><![CDATA[x\ny]]></Description>\r\n"));
+    assert!(output.contains("<Changelog value=\"line1\nline2\"/>\r\n"));
+
+    let result = Modinfo::from_str(&output).unwrap();
+    assert_eq!(result.description(), Some("x\ny"));
+    assert_eq!(result.get_changelog(), Some("line1\nline2"));
+    assert_eq!(result, modinfo);
+}
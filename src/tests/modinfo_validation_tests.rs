@@ -0,0 +1,198 @@
+use super::*;
+
+#[test]
+fn validate_schema_v2_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+
+    assert!(modinfo.validate_schema(ModinfoVersion::V2).is_ok());
+}
+
+#[test]
+fn validate_schema_v1_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v1()).unwrap();
+
+    assert!(modinfo.validate_schema(ModinfoVersion::V1).is_ok());
+}
+
+#[test]
+fn validate_schema_unknown_tag_test() {
+    let xml = fixtures::xml_string_v2().replace("<Website", r#"<Foo value="bar" /><Website"#);
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let errors = modinfo.validate_schema(ModinfoVersion::V2).unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], ModinfoError::UnknownTag(tag) if tag == "Foo"));
+}
+
+#[test]
+fn validate_schema_wrong_version_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let errors = modinfo.validate_schema(ModinfoVersion::V1).unwrap_err();
+
+    assert!(errors
+        .iter()
+        .any(|err| matches!(err, ModinfoError::InvalidRoot(root) if root == "xml")));
+    assert!(errors
+        .iter()
+        .any(|err| matches!(err, ModinfoError::UnknownTag(tag) if tag == "Website")));
+    assert!(errors
+        .iter()
+        .any(|err| matches!(err, ModinfoError::UnknownTag(tag) if tag == "DisplayName")));
+}
+
+#[test]
+fn validate_schema_missing_value_test() {
+    let xml = fixtures::xml_string_v2().replace(r#"<Author value="Name" />"#, "<Author />");
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let errors = modinfo.validate_schema(ModinfoVersion::V2).unwrap_err();
+
+    assert_eq!(errors.len(), 1);
+    assert!(matches!(&errors[0], ModinfoError::NoValueAttribute(tag) if tag == "Author"));
+}
+
+#[test]
+fn validate_name_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+
+    assert!(modinfo.validate_name().is_ok());
+}
+
+#[test]
+fn validate_name_invalid_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+
+    for name in ["Some/Mod", "Some\\Mod", " SomeMod", "SomeMod ", ""] {
+        modinfo.set_value_for("name", name);
+        assert!(matches!(modinfo.validate_name(), Err(ModinfoError::InvalidName(n)) if n == name));
+    }
+}
+
+#[test]
+fn scaffold_test() {
+    let modinfo = Modinfo::scaffold("MyMod", "Me");
+
+    assert!(modinfo.validate().is_ok());
+    assert_eq!(modinfo.name(), Some("MyMod"));
+    assert_eq!(modinfo.author(), Some("Me"));
+    assert_eq!(modinfo.get_version(), &semver::Version::new(0, 1, 0));
+}
+
+#[test]
+fn scaffold_round_trip_test() {
+    let modinfo = Modinfo::scaffold("MyMod", "Me");
+    let options = WriteOptions {
+        omit_empty: true,
+        ..Default::default()
+    };
+    let result = Modinfo::from_str(&modinfo.to_string_with_options(&options)).unwrap();
+
+    assert!(result.validate().is_ok());
+    assert_eq!(result, modinfo);
+}
+
+#[test]
+fn format_consistency_test() {
+    for xml in [fixtures::xml_string_v1(), fixtures::xml_string_v2()] {
+        assert!(Modinfo::from_str(&xml).unwrap().format_consistency().is_ok());
+    }
+}
+
+#[test]
+fn format_consistency_declaration_v1_root_test() {
+    let xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>{}"#,
+        fixtures::xml_string_v1().trim()
+    );
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+
+    assert!(modinfo.is_v1());
+    assert!(matches!(
+        modinfo.format_consistency(),
+        Err(ModinfoError::InconsistentFormat(_))
+    ));
+}
+
+#[test]
+fn format_consistency_v2_field_v1_root_test() {
+    let xml = fixtures::xml_string_v1().replace("</ModInfo>", r#"<Website value="HP" /></ModInfo>"#);
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+
+    assert!(matches!(
+        modinfo.format_consistency(),
+        Err(ModinfoError::InconsistentFormat(message)) if message.contains("Website")
+    ));
+}
+
+#[test]
+fn validate_missing_version_test() {
+    let xml = fixtures::xml_string_v2().replace(r#"<Version value="2.3.4" compat="A99" />"#, "");
+    let mut modinfo = Modinfo::from_str(&xml).unwrap();
+
+    assert!(matches!(modinfo.validate(), Err(ModinfoError::NoModinfoVersion)));
+
+    modinfo.set_version("1.0.0".to_owned());
+    assert!(modinfo.validate().is_ok());
+}
+
+#[test]
+fn validate_invalid_version_is_present_test() {
+    let xml = fixtures::xml_string_v2().replace("2.3.4", "banana");
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+
+    assert!(modinfo.validate().is_ok());
+}
+
+#[test]
+fn completeness_full_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+
+    assert_eq!(modinfo.completeness(), 100);
+}
+
+#[test]
+fn completeness_required_only_test() {
+    let xml = fixtures::xml_string_v2()
+        .replace(r#"<DisplayName value="Official Mod Name" />"#, "")
+        .replace(r#"<Website value="HP" />"#, "")
+        .replace(r#" compat="A99""#, "");
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+
+    assert_eq!(modinfo.completeness(), 80);
+    assert!(modinfo.validate().is_ok());
+}
+
+#[test]
+fn completeness_default_test() {
+    assert_eq!(Modinfo::default().completeness(), 20);
+}
+
+#[test]
+fn from_map_test() {
+    let mut map: HashMap<String, String> = [
+        ("name", "SomeMod"),
+        ("version", "1.2.3"),
+        ("compat", "A21"),
+        ("author", "Me"),
+        ("description", "Does things"),
+        ("modinfo_version", "V1"),
+        ("unknown", "ignored"),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_owned(), value.to_owned()))
+    .collect();
+
+    let modinfo = Modinfo::from_map(&map).unwrap();
+    assert!(modinfo.validate().is_ok());
+    assert!(modinfo.is_v1());
+    assert_eq!(modinfo.get_version(), &semver::Version::new(1, 2, 3));
+    assert_eq!(modinfo.compat(), Some("A21"));
+
+    map.insert("version".to_owned(), "banana".to_owned());
+    assert!(matches!(
+        Modinfo::from_map(&map),
+        Err(ModinfoError::InvalidVersionString(_))
+    ));
+
+    map.remove("version");
+    assert!(matches!(Modinfo::from_map(&map), Err(ModinfoError::NoModinfoVersion)));
+}
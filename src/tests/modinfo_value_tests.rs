@@ -0,0 +1,401 @@
+use super::*;
+
+#[test]
+fn try_set_value_for_test() {
+    let mut modinfo = Modinfo::new();
+
+    assert!(modinfo.try_set_value_for("author", "x").is_ok());
+    assert_eq!(modinfo.author(), Some("x"));
+
+    assert!(modinfo.try_set_value_for("Website", "https://example.org").is_ok());
+    assert_eq!(modinfo.website(), Some("https://example.org"));
+
+    assert!(matches!(
+        modinfo.try_set_value_for("nope", "x"),
+        Err(ModinfoError::UnknownTag(field)) if field == "nope"
+    ));
+}
+
+#[test]
+fn try_set_value_for_version_test() {
+    let mut modinfo = Modinfo::new();
+
+    assert!(modinfo.try_set_value_for("version", "1.2").is_ok());
+    assert_eq!(modinfo.get_version(), &Version::new(1, 2, 0));
+
+    assert!(matches!(
+        modinfo.try_set_value_for("version", "banana"),
+        Err(ModinfoError::InvalidVersionString(_))
+    ));
+    assert_eq!(modinfo.get_version(), &Version::new(1, 2, 0));
+}
+
+#[test]
+fn get_value_for_version_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v1()).unwrap();
+
+    assert_eq!(modinfo.get_value_for("version"), Some(&Cow::from("1.2.3")));
+    assert_eq!(modinfo.get_value_for("Version"), Some(&Cow::from("1.2.3")));
+    assert_eq!(modinfo.get_value_for("compat"), Some(&Cow::from("A99")));
+    assert_eq!(modinfo.get_owned_value_for("version"), Some(String::from("1.2.3")));
+
+    modinfo.set_version("2.0".to_owned());
+    assert_eq!(modinfo.get_value_for("version"), Some(&Cow::from("2.0.0")));
+    modinfo.bump_version_minor();
+    assert_eq!(modinfo.get_value_for("version"), Some(&Cow::from("2.1.0")));
+    modinfo.bump_pre();
+    assert_eq!(modinfo.get_value_for("version"), Some(&Cow::from("2.1.0-rc.1")));
+    modinfo.clear_value_for("version");
+    assert_eq!(modinfo.get_value_for("version"), Some(&Cow::from("0.1.0")));
+}
+
+#[test]
+fn best_name_test() {
+    let mut modinfo = Modinfo::new();
+    assert_eq!(modinfo.best_name(), None);
+    assert_eq!(modinfo.sort_key(), "");
+
+    modinfo.set_value_for("name", "SomeMod");
+    assert_eq!(modinfo.best_name(), Some("SomeMod"));
+
+    modinfo.set_value_for("display_name", "");
+    assert_eq!(modinfo.best_name(), Some("SomeMod"));
+
+    modinfo.set_value_for("display_name", "Some Mod");
+    assert_eq!(modinfo.best_name(), Some("Some Mod"));
+    assert_eq!(modinfo.sort_key(), "some mod");
+}
+
+#[test]
+fn sort_key_test() {
+    let mut names: Vec<Modinfo> = ["beta", "Alpha", "Gamma"]
+        .iter()
+        .map(|name| {
+            let mut modinfo = Modinfo::new();
+            modinfo.set_value_for("display_name", name);
+            modinfo
+        })
+        .collect();
+
+    names.sort_by_key(|modinfo| modinfo.sort_key());
+
+    assert_eq!(
+        names.iter().map(|m| m.best_name().unwrap()).collect::<Vec<_>>(),
+        vec!["Alpha", "beta", "Gamma"]
+    );
+}
+
+#[test]
+fn clear_value_for_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    modinfo.set_value_for("website", "https://example.org");
+    modinfo.clear_value_for("Website");
+
+    assert_eq!(modinfo.get_value_for("website"), None);
+    assert!(modinfo.to_string().contains(r#"<Website value=""/>"#));
+
+    let options = WriteOptions {
+        omit_empty: true,
+        ..Default::default()
+    };
+    let output = modinfo.to_string_with_options(&options);
+    assert!(!output.contains("<Website"));
+    assert!(output.contains("<Name "));
+}
+
+#[test]
+fn clear_value_for_version_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2().replace("2.3.4", "banana")).unwrap();
+    assert!(modinfo.version_parse_error().is_some());
+
+    modinfo.clear_value_for("version");
+
+    assert_eq!(modinfo.get_version(), &semver::Version::new(0, 1, 0));
+    assert_eq!(modinfo.compat(), None);
+    assert!(modinfo.meta.version_missing);
+    assert_eq!(modinfo.version_parse_error(), None);
+    assert!(matches!(modinfo.validate(), Err(ModinfoError::NoModinfoVersion)));
+
+    modinfo.set_version("1.0.0".to_owned());
+    assert!(!modinfo.meta.version_missing);
+}
+
+#[test]
+fn to_ordered_map_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let map = modinfo.to_ordered_map();
+    let keys: Vec<&str> = map.iter().map(|(k, _)| k.as_str()).collect();
+
+    assert_eq!(
+        keys,
+        vec![
+            "name",
+            "display_name",
+            "version",
+            "compat",
+            "description",
+            "author",
+            "website"
+        ]
+    );
+    assert_eq!(map[2], ("version".to_string(), "2.3.4".to_string()));
+    assert_eq!(map[3], ("compat".to_string(), "A99".to_string()));
+}
+
+#[test]
+fn apply_patch_test() {
+    let original = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let mut modinfo = original.clone();
+    modinfo
+        .apply_patch(&ModinfoPatch {
+            website: Some("https://example.org".to_owned()),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(modinfo.website(), Some("https://example.org"));
+    for field in [
+        "name",
+        "display_name",
+        "version",
+        "compat",
+        "description",
+        "author",
+        "changelog",
+    ] {
+        assert_eq!(modinfo.get_owned_value_for(field), original.get_owned_value_for(field));
+    }
+}
+
+#[test]
+fn apply_patch_invalid_version_test() {
+    let original = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let mut modinfo = original.clone();
+
+    assert!(matches!(
+        modinfo.apply_patch(&ModinfoPatch {
+            website: Some("https://example.org".to_owned()),
+            version: Some("banana".to_owned()),
+            ..Default::default()
+        }),
+        Err(ModinfoError::InvalidVersionString(_))
+    ));
+    assert_eq!(modinfo.get_version(), original.get_version());
+    assert_eq!(modinfo.website(), original.website());
+}
+
+#[test]
+fn rename_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let suggested = modinfo.rename("BetterMod", true);
+
+    assert_eq!(modinfo.name(), Some("BetterMod"));
+    assert_eq!(modinfo.display_name(), Some("Better Mod"));
+    assert_eq!(suggested, None);
+}
+
+#[test]
+fn rename_keep_display_name_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    modinfo.set_file_path(PathBuf::from("Mods/SomeInternalName/ModInfo.xml"));
+    let suggested = modinfo.rename("BetterMod", false);
+
+    assert_eq!(modinfo.name(), Some("BetterMod"));
+    assert_eq!(modinfo.display_name(), Some("Official Mod Name"));
+    assert_eq!(suggested, Some(PathBuf::from("Mods/BetterMod/ModInfo.xml")));
+}
+
+#[test]
+fn priority_round_trip_test() {
+    let xml = fixtures::xml_string_v2().replace("</xml>", r#"<Priority value="10" /></xml>"#);
+    let modinfo = Modinfo::from_str(&xml).unwrap();
+    let output = modinfo.to_string();
+
+    assert_eq!(modinfo.get_priority(), Some(10));
+    assert!(output.contains(r#"<Priority value="10"/>"#));
+    assert_eq!(Modinfo::from_str(&output).unwrap().get_priority(), Some(10));
+}
+
+#[test]
+fn priority_invalid_test() {
+    let xml = fixtures::xml_string_v2().replace("</xml>", r#"<Priority value="high" /></xml>"#);
+
+    assert!(matches!(
+        Modinfo::from_str(&xml),
+        Err(ModinfoError::InvalidPriority(value)) if value == "high"
+    ));
+    assert!(matches!(
+        Modinfo::default().try_set_value_for("priority", "high"),
+        Err(ModinfoError::InvalidPriority(_))
+    ));
+}
+
+#[test]
+fn from_tuple_test() {
+    let modinfo = Modinfo::from(("MyMod", "1.0.0", "Me"));
+
+    assert_eq!(modinfo.name(), Some("MyMod"));
+    assert_eq!(modinfo.get_version(), &semver::Version::new(1, 0, 0));
+    assert_eq!(modinfo.author(), Some("Me"));
+    assert_eq!(modinfo.get_modinfo_version(), ModinfoVersion::V2);
+    assert!(modinfo.validate().is_ok());
+}
+
+#[test]
+fn non_default_fields_test() {
+    let mut modinfo = Modinfo::default();
+    assert!(modinfo.non_default_fields().is_empty());
+
+    modinfo.set_value_for("name", "SomeMod");
+    modinfo.set_value_for("author", "Me");
+
+    assert_eq!(
+        modinfo.non_default_fields(),
+        vec![ModinfoField::Name, ModinfoField::Author]
+    );
+}
+
+#[test]
+fn enable_mod_parse_test() {
+    for (value, expected) in [("true", true), ("FALSE", false), ("1", true), ("0", false)] {
+        let xml = fixtures::xml_string_v2().replace("</xml>", &format!(r#"<EnableMod value="{}" /></xml>"#, value));
+        let modinfo = Modinfo::from_str(&xml).unwrap();
+
+        assert_eq!(modinfo.is_enabled(), Some(expected), "{}", value);
+    }
+}
+
+#[test]
+fn enable_mod_round_trip_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    assert_eq!(modinfo.is_enabled(), None);
+    assert!(!modinfo.to_string().contains("EnableMod"));
+
+    modinfo.set_enabled(Some(false));
+    let output = modinfo.to_string();
+
+    assert!(output.contains(r#"<EnableMod value="false"/>"#));
+    assert_eq!(Modinfo::from_str(&output).unwrap().is_enabled(), Some(false));
+}
+
+#[test]
+fn enable_mod_invalid_test() {
+    let xml = fixtures::xml_string_v2().replace("</xml>", r#"<EnableMod value="maybe" /></xml>"#);
+
+    assert!(matches!(
+        Modinfo::from_str(&xml),
+        Err(ModinfoError::InvalidEnableMod(value)) if value == "maybe"
+    ));
+}
+
+#[test]
+fn summary_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+
+    assert_eq!(modinfo.summary(), "Official Mod Name v2.3.4 (A99) by Name");
+}
+
+#[test]
+fn summary_minimal_test() {
+    let mut modinfo = Modinfo::default();
+    modinfo.set_value_for("name", "SomeMod");
+    modinfo.set_value_for("author", "");
+
+    assert_eq!(modinfo.summary(), "SomeMod v0.1.0");
+    assert_eq!(Modinfo::default().summary(), "v0.1.0");
+}
+
+#[test]
+fn display_name_is_explicit_test() {
+    let v2 = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let v1 = Modinfo::from_str(&fixtures::xml_string_v1()).unwrap();
+
+    assert!(v2.display_name_is_explicit());
+    assert!(!v1.display_name_is_explicit());
+}
+
+#[test]
+fn display_name_is_explicit_after_set_test() {
+    let mut modinfo = Modinfo::scaffold("SomeMod", "Me");
+    assert!(!modinfo.display_name_is_explicit());
+
+    modinfo.set_value_for("display_name", "Some Better Mod");
+    assert!(modinfo.display_name_is_explicit());
+
+    modinfo.clear_value_for("display_name");
+    assert!(!modinfo.display_name_is_explicit());
+}
+
+#[test]
+fn install_path_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+
+    assert_eq!(
+        modinfo.install_path("/game/Mods"),
+        Some(PathBuf::from("/game/Mods/SomeInternalName/ModInfo.xml"))
+    );
+}
+
+#[test]
+fn mod_folder_name_test() {
+    let mut modinfo = Modinfo::new();
+    modinfo.set_file_path(PathBuf::from("/Mods/MyMod/ModInfo.xml"));
+
+    assert_eq!(modinfo.mod_dir(), Some(Path::new("/Mods/MyMod")));
+    assert_eq!(modinfo.mod_folder_name(), Some("MyMod"));
+}
+
+#[test]
+fn mod_folder_name_without_dir_test() {
+    let mut modinfo = Modinfo::new();
+    assert_eq!(modinfo.mod_folder_name(), None);
+
+    modinfo.set_file_path(PathBuf::from("ModInfo.xml"));
+    assert_eq!(modinfo.mod_dir(), None);
+    assert_eq!(modinfo.mod_folder_name(), None);
+}
+
+#[test]
+fn check_folder_name_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    assert!(modinfo.check_folder_name().is_ok());
+
+    modinfo.set_file_path(PathBuf::from("/Mods/SomeInternalName/ModInfo.xml"));
+    assert!(modinfo.check_folder_name().is_ok());
+
+    modinfo.set_file_path(PathBuf::from("/Mods/RenamedMod/ModInfo.xml"));
+    assert!(matches!(
+        modinfo.check_folder_name(),
+        Err(ModinfoError::FolderNameMismatch { folder, name }) if folder == "RenamedMod" && name == "SomeInternalName"
+    ));
+}
+
+#[test]
+fn install_path_invalid_name_test() {
+    let mut modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+
+    for name in ["", "../Escape"] {
+        modinfo.set_value_for("name", name);
+        assert_eq!(modinfo.install_path("/game/Mods"), None);
+    }
+}
+
+#[test]
+fn split_name_display_test() {
+    assert_eq!(
+        split_name_display("SomeMod (Cool Mod)"),
+        ("SomeMod".to_owned(), Some("Cool Mod".to_owned()))
+    );
+    assert_eq!(split_name_display("SomeMod"), ("SomeMod".to_owned(), None));
+    assert_eq!(
+        split_name_display(" SomeMod (Cool (Big) Mod) "),
+        ("SomeMod".to_owned(), Some("Cool (Big) Mod".to_owned()))
+    );
+}
+
+#[test]
+fn split_name_display_unsplittable_test() {
+    for input in ["(Cool Mod)", "SomeMod ()", "SomeMod Cool Mod)", "SomeMod (Cool Mod"] {
+        assert_eq!(split_name_display(input), (input.to_owned(), None));
+    }
+}
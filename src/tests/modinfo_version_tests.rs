@@ -0,0 +1,92 @@
+use super::*;
+
+#[test]
+fn modinfo_version_display_test() {
+    assert_eq!(ModinfoVersion::V1.to_string(), "V1");
+    assert_eq!(ModinfoVersion::V2.to_string(), "V2");
+}
+
+#[test]
+fn modinfo_version_from_str_test() {
+    assert_eq!(ModinfoVersion::from_str("v1").unwrap(), ModinfoVersion::V1);
+    assert_eq!(ModinfoVersion::from_str("V2").unwrap(), ModinfoVersion::V2);
+    assert!(matches!(
+        ModinfoVersion::from_str("V3"),
+        Err(ModinfoError::UnknownModinfoVersion(_))
+    ));
+
+    for version in [ModinfoVersion::V1, ModinfoVersion::V2] {
+        assert_eq!(ModinfoVersion::from_str(&version.to_string()).unwrap(), version);
+    }
+}
+
+#[test]
+fn modinfo_is_v1_v2_test() {
+    let mut modinfo = Modinfo::default();
+
+    assert!(modinfo.is_v2());
+    assert!(!modinfo.is_v1());
+
+    modinfo.set_modinfo_version(ModinfoVersion::V1);
+
+    assert!(modinfo.is_v1());
+    assert!(!modinfo.is_v2());
+}
+
+#[test]
+fn modinfo_new_with_version_test() {
+    let v1 = Modinfo::new_with_version(ModinfoVersion::V1);
+    let v2 = Modinfo::new_with_version(ModinfoVersion::V2);
+
+    assert!(v1.is_v1());
+    assert!(!v1.to_string().contains("<?xml"));
+    assert!(v1.to_string().contains("<ModInfo>"));
+    assert!(v2.to_string().starts_with("<?xml"));
+    assert_eq!(Modinfo::new().get_modinfo_version(), ModinfoVersion::latest());
+}
+
+#[test]
+fn modinfo_to_v1_lossy_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v2()).unwrap();
+    let (result, warnings) = modinfo.to_v1_lossy();
+
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings.iter().any(|w| w.contains("website") && w.contains("HP")));
+    assert!(warnings
+        .iter()
+        .any(|w| w.contains("display_name") && w.contains("Official Mod Name")));
+
+    let xml = result.to_string();
+
+    assert!(result.is_v1());
+    assert!(xml.contains("<ModInfo>"));
+    assert!(!xml.contains("<?xml"));
+    assert!(!xml.contains("Website"));
+    assert!(!xml.contains("DisplayName"));
+}
+
+#[test]
+fn modinfo_to_v1_lossy_no_warnings_test() {
+    let modinfo = Modinfo::from_str(&fixtures::xml_string_v1()).unwrap();
+    let (result, warnings) = modinfo.to_v1_lossy();
+
+    assert!(warnings.is_empty());
+    assert_eq!(result, modinfo);
+}
+
+#[test]
+fn upgrade_v1_test() {
+    let xml = fixtures::xml_string_v1().replace("1.2.3", "1.2");
+    let mut modinfo = Modinfo::from_str(&xml).unwrap();
+    modinfo.upgrade();
+    let output = modinfo.to_string();
+
+    assert!(output.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+    assert!(output.contains("<xml>"));
+    assert!(output.contains(r#"<DisplayName value="Some Internal Name"/>"#));
+    assert!(output.contains(r#"<Version value="1.2.0" compat="A99"/>"#));
+
+    let result = Modinfo::from_str(&output).unwrap();
+    assert!(result.is_v2());
+    assert!(result.validate_schema(ModinfoVersion::V2).is_ok());
+}
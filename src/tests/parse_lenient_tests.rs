@@ -0,0 +1,75 @@
+use super::*;
+
+fn adversarial_inputs() -> Vec<Vec<u8>> {
+    vec![
+        // truncated XML
+        br#"<?xml version="1.0" encoding="UTF-8"?><xml><Name value="Some"#.to_vec(),
+        // huge attribute
+        format!(r#"<xml><Description value="{}" /></xml>"#, "x".repeat(1_000_000)).into_bytes(),
+        // nested elements
+        br#"<xml><Name><Name><Author value="Me" /></Name></Name></xml>"#.to_vec(),
+        // non-UTF-8
+        b"<xml><Name value=\"\xff\xfe\" /></xml>".to_vec(),
+        // missing value attribute
+        br#"<xml><Name /><Version compat="A21" /></xml>"#.to_vec(),
+        // invalid version
+        br#"<xml><Version value="banana" /></xml>"#.to_vec(),
+        // duplicate attributes
+        br#"<xml><Name value="a" value="b" /></xml>"#.to_vec(),
+        // garbage
+        b"\x00\x01<<>>&&;".to_vec(),
+        Vec::new(),
+    ]
+}
+
+#[test]
+fn parse_lenient_never_panics_test() {
+    for input in adversarial_inputs() {
+        let result = std::panic::catch_unwind(|| parse_lenient(&input));
+
+        assert!(
+            result.is_ok(),
+            "panicked on input {:?}",
+            String::from_utf8_lossy(&input)
+        );
+    }
+}
+
+#[test]
+fn parse_lenient_non_utf8_test() {
+    assert!(matches!(
+        parse_lenient(b"<xml><Name value=\"\xff\" /></xml>"),
+        Err(ModinfoError::XMLError(_))
+    ));
+}
+
+#[test]
+fn parse_lenient_invalid_version_test() {
+    let result = parse_lenient(br#"<xml><Version value="banana" /></xml>"#).unwrap();
+
+    assert_eq!(result.get_version().to_string(), "0.0.0");
+    assert!(result.version_parse_error().is_some());
+}
+
+#[test]
+fn parse_lenient_valid_test() {
+    let result = parse_lenient(fixtures::xml_string_v2().as_bytes()).unwrap();
+
+    assert_eq!(result, Modinfo::from_str(&fixtures::xml_string_v2()).unwrap());
+}
+
+#[test]
+fn sniff_v1_test() {
+    let (modinfo, version) = sniff(fixtures::xml_string_v1().as_bytes()).unwrap();
+
+    assert_eq!(version, ModinfoVersion::V1);
+    assert_eq!(modinfo, Modinfo::from_str(&fixtures::xml_string_v1()).unwrap());
+}
+
+#[test]
+fn sniff_v2_test() {
+    let (modinfo, version) = sniff(fixtures::xml_string_v2().as_bytes()).unwrap();
+
+    assert_eq!(version, ModinfoVersion::V2);
+    assert_eq!(modinfo, Modinfo::from_str(&fixtures::xml_string_v2()).unwrap());
+}
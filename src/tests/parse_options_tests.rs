@@ -0,0 +1,196 @@
+use super::*;
+
+#[test]
+fn parse_options_default_test() {
+    let options = ParseOptions::default();
+
+    assert!(options.trim_text);
+    assert!(options.accept_root.is_empty());
+    assert_eq!(options.version_fallback, Some(Version::new(0, 0, 0)));
+    assert!(!options.strict_semver);
+}
+
+#[test]
+fn from_str_with_options_no_trim_text_test() {
+    let xml = fixtures::xml_string_v1_text_content()
+        .replace("<Name>SomeInternalName</Name>", "<Name>  SomeInternalName  </Name>");
+    let options = ParseOptions {
+        trim_text: false,
+        ..Default::default()
+    };
+    let result = Modinfo::from_str_with_options(&xml, &options).unwrap();
+
+    assert_eq!(result.name.value, Some("  SomeInternalName  ".into()));
+}
+
+#[test]
+fn from_str_with_options_default_matches_from_str_test() {
+    let xml = fixtures::xml_string_v1_text_content()
+        .replace("<Name>SomeInternalName</Name>", "<Name>  SomeInternalName  </Name>");
+    let result = Modinfo::from_str_with_options(&xml, &ParseOptions::default()).unwrap();
+
+    assert_eq!(result, Modinfo::from_str(&xml).unwrap());
+    assert_eq!(result.name.value, Some("SomeInternalName".into()));
+}
+
+#[test]
+fn from_str_with_options_accept_root_test() {
+    let xml = fixtures::xml_string_custom_root();
+    let options = ParseOptions {
+        accept_root: vec!["Mod".to_owned()],
+        ..Default::default()
+    };
+    let result = Modinfo::from_str_with_options(&xml, &options).unwrap();
+
+    assert_eq!(result.meta.version, ModinfoVersion::V2);
+    assert_eq!(result.name.value, Some("SomeInternalName".into()));
+    assert_eq!(result.display_name.value, Some("Official Mod Name".into()));
+    assert_eq!(result.author.value, Some("Name".into()));
+    assert_eq!(result.website.value, Some("HP".into()));
+    assert_eq!(result.get_version(), &Version::new(2, 3, 4));
+}
+
+#[test]
+fn from_str_unknown_root_tolerated_test() {
+    let result = Modinfo::from_str(&fixtures::xml_string_custom_root()).unwrap();
+
+    assert_eq!(result.meta.version, ModinfoVersion::V1);
+    assert_eq!(result.name.value, Some("SomeInternalName".into()));
+}
+
+#[test]
+fn from_str_with_options_version_fallback_test() {
+    let xml = fixtures::xml_string_v1().replace("1.2.3", "banana");
+
+    let default = Modinfo::from_str_with_options(&xml, &ParseOptions::default()).unwrap();
+    assert_eq!(default.get_version().to_string(), "0.0.0");
+    assert!(default.meta.version_parse_error.is_some());
+
+    let options = ParseOptions {
+        version_fallback: Some(Version::new(1, 0, 0)),
+        ..Default::default()
+    };
+    let result = Modinfo::from_str_with_options(&xml, &options).unwrap();
+    assert_eq!(result.get_version(), &Version::new(1, 0, 0));
+
+    let options = ParseOptions {
+        version_fallback: None,
+        ..Default::default()
+    };
+    assert!(matches!(
+        Modinfo::from_str_with_options(&xml, &options),
+        Err(ModinfoError::InvalidVersionString(_))
+    ));
+}
+
+#[test]
+fn set_version_with_options_fallback_test() {
+    let mut modinfo = Modinfo::new();
+
+    let options = ParseOptions {
+        version_fallback: Some(Version::new(1, 0, 0)),
+        ..Default::default()
+    };
+    modinfo.set_version_with_options("banana", &options).unwrap();
+    assert_eq!(modinfo.get_version(), &Version::new(1, 0, 0));
+
+    let options = ParseOptions {
+        version_fallback: None,
+        ..Default::default()
+    };
+    assert!(matches!(
+        modinfo.set_version_with_options("banana", &options),
+        Err(ModinfoError::InvalidVersionString(_))
+    ));
+    assert_eq!(modinfo.get_version(), &Version::new(1, 0, 0));
+
+    modinfo.set_version_with_options("2.0", &options).unwrap();
+    assert_eq!(modinfo.get_version(), &Version::new(2, 0, 0));
+}
+
+#[test]
+fn from_str_with_options_strict_semver_test() {
+    let strict = ParseOptions {
+        strict_semver: true,
+        ..Default::default()
+    };
+    let lenient = ParseOptions::default();
+
+    for version in ["1.2", "v1.2.3"] {
+        let xml = fixtures::xml_string_v1().replace("1.2.3", version);
+
+        assert!(matches!(
+            Modinfo::from_str_with_options(&xml, &strict),
+            Err(ModinfoError::InvalidVersionString(_))
+        ));
+        assert!(Modinfo::from_str_with_options(&xml, &lenient).is_ok());
+    }
+
+    let xml = fixtures::xml_string_v1().replace("1.2.3", "1.2.0");
+    let result = Modinfo::from_str_with_options(&xml, &strict).unwrap();
+
+    assert_eq!(result.get_version(), &Version::new(1, 2, 0));
+}
+
+#[test]
+fn from_str_with_options_max_attribute_len_test() {
+    let xml = format!(
+        r#"<xml><Name value="SomeMod" /><Description value="{}" /></xml>"#,
+        "x".repeat(10_000)
+    );
+    let options = ParseOptions {
+        max_attribute_len: Some(1024),
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        Modinfo::from_str_with_options(&xml, &options),
+        Err(ModinfoError::TooLarge {
+            size: 10_000,
+            limit: 1024
+        })
+    ));
+    assert_eq!(Modinfo::from_str(&xml).unwrap().description().unwrap().len(), 10_000);
+}
+
+#[test]
+fn from_str_with_options_max_attribute_len_raw_test() {
+    let options = ParseOptions {
+        max_attribute_len: Some(16),
+        ..Default::default()
+    };
+
+    // every attribute is checked, not just `value`
+    let xml = format!(r#"<xml><Version value="1.0.0" compat="{}" /></xml>"#, "A".repeat(32));
+    assert!(matches!(
+        Modinfo::from_str_with_options(&xml, &options),
+        Err(ModinfoError::TooLarge { size: 32, limit: 16 })
+    ));
+
+    // the escaped source is measured, not the (shorter) unescaped value
+    let xml = format!(r#"<xml><Name value="{}" /></xml>"#, "&amp;".repeat(4));
+    assert!(matches!(
+        Modinfo::from_str_with_options(&xml, &options),
+        Err(ModinfoError::TooLarge { size: 20, limit: 16 })
+    ));
+}
+
+#[test]
+fn from_str_with_options_max_total_len_test() {
+    let xml = fixtures::xml_string_v2();
+    let options = ParseOptions {
+        max_total_len: Some(64),
+        ..Default::default()
+    };
+
+    assert!(matches!(
+        Modinfo::from_str_with_options(&xml, &options),
+        Err(ModinfoError::TooLarge { limit: 64, .. })
+    ));
+
+    let options = ParseOptions {
+        max_total_len: Some(xml.len()),
+        ..Default::default()
+    };
+    assert!(Modinfo::from_str_with_options(&xml, &options).is_ok());
+}
@@ -9,14 +9,74 @@ pub trait VersionTools {
     fn add_build(&mut self, build: &str);
 }
 
+/// Increments the numeric suffix of the pre-release (`rc.1` becomes `rc.2`), and removes any build data
+///
+/// A purely numeric pre-release is incremented as a whole (`1` becomes `2`), one without a numeric
+/// suffix gets `.1` appended (`beta` becomes `beta.1`), and a version without a pre-release becomes
+/// the first release candidate (`rc.1`). A number which can't be incremented any further is kept,
+/// and `.1` appended after it.
+pub(crate) fn bump_pre(version: &mut Version) {
+    let (label, number) = match version.pre.as_str().rsplit_once('.') {
+        Some((label, number)) => (Some(label), number),
+        None => (None, version.pre.as_str()),
+    };
+    let next = number.parse::<u64>().ok().and_then(|number| number.checked_add(1));
+    let pre = match (label, next) {
+        _ if version.pre.is_empty() => String::from("rc.1"),
+        (Some(label), Some(next)) => format!("{}.{}", label, next),
+        (None, Some(next)) => next.to_string(),
+        _ => format!("{}.1", version.pre),
+    };
+
+    version.pre = Prerelease::new(&pre).unwrap_or(Prerelease::EMPTY);
+    version.build = BuildMetadata::EMPTY;
+}
+
+/// Leniently parses a version string
+///
+/// If the version can't be parsed, it falls back to a plain `0.0.0`, see `try_parse_version`.
+pub(crate) fn parse_version(version: &str) -> Version {
+    try_parse_version(version).0
+}
+
+/// Leniently parses a version string, also returning the parse error when it fell back to `0.0.0`
+pub(crate) fn try_parse_version(version: &str) -> (Version, Option<String>) {
+    match lenient_semver::parse_into::<Version>(version) {
+        Ok(result) => (result, None),
+        Err(err) => (Version::new(0, 0, 0), Some(err.to_string())),
+    }
+}
+
+/// Parses a version string according to the given `ParseOptions`
+///
+/// With `strict_semver` the version must be a complete `MAJOR.MINOR.PATCH` semver,
+/// otherwise it is parsed leniently and `version_fallback` is used if it can't be parsed.
+/// A `None` fallback returns `ModinfoError::InvalidVersionString` instead.
+///
+/// When the fallback is used, the parse error is returned alongside it.
+pub(crate) fn parse_version_with_options(
+    version: &str,
+    options: &ParseOptions,
+) -> Result<(Version, Option<String>), ModinfoError> {
+    if options.strict_semver {
+        return match Version::parse(version) {
+            Ok(result) => Ok((result, None)),
+            Err(err) => Err(ModinfoError::InvalidVersionString(err.to_string())),
+        };
+    }
+
+    match lenient_semver::parse_into::<Version>(version) {
+        Ok(result) => Ok((result, None)),
+        Err(err) => match &options.version_fallback {
+            Some(fallback) => Ok((fallback.clone(), Some(err.to_string()))),
+            None => Err(ModinfoError::InvalidVersionString(err.to_string())),
+        },
+    }
+}
+
 impl VersionTools for Version {
     fn set_version(&mut self, version: &str) {
-        *self = match lenient_semver::parse_into::<Version>(version) {
-            Ok(result) => result,
-            Err(err) => {
-                lenient_semver::parse_into::<Version>(format!("0.0.0+{}", err).as_ref()).unwrap()
-            }
-        };
+        *self = parse_version(version);
     }
 
     fn bump_major(&mut self) {
@@ -48,3 +108,78 @@ impl VersionTools for Version {
         self.pre = Prerelease::new(pre).unwrap();
     }
 }
+
+/// A scheme for bumping a version, used by `Modinfo::bump_with`
+///
+/// # Example
+///
+/// ```rust
+/// use modinfo::{BumpStrategy, Modinfo};
+///
+/// struct DoublePatch;
+///
+/// impl BumpStrategy for DoublePatch {
+///     fn bump(&self, version: &mut semver::Version) {
+///         version.patch += 2;
+///     }
+/// }
+///
+/// let mut modinfo = Modinfo::default();
+/// modinfo.bump_with(DoublePatch);
+///
+/// assert_eq!(modinfo.get_version(), &semver::Version::new(0, 1, 2));
+/// ```
+pub trait BumpStrategy {
+    fn bump(&self, version: &mut Version);
+}
+
+/// Bumps the Major version, see `Modinfo::bump_version_major`
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct SemverMajor;
+
+/// Bumps the Minor version, see `Modinfo::bump_version_minor`
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct SemverMinor;
+
+/// Bumps the Patch version, see `Modinfo::bump_version_patch`
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct SemverPatch;
+
+/// Calendar versioning as `YEAR.MONTH.RELEASE`, e.g. `2024.3.0`
+///
+/// The release number counts up within the same year and month, and restarts at 0 otherwise.
+/// Pre-release and build data are removed.
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct CalVer {
+    pub year: u64,
+    pub month: u64,
+}
+
+impl BumpStrategy for SemverMajor {
+    fn bump(&self, version: &mut Version) {
+        version.bump_major();
+    }
+}
+
+impl BumpStrategy for SemverMinor {
+    fn bump(&self, version: &mut Version) {
+        version.bump_minor();
+    }
+}
+
+impl BumpStrategy for SemverPatch {
+    fn bump(&self, version: &mut Version) {
+        version.bump_patch();
+    }
+}
+
+impl BumpStrategy for CalVer {
+    fn bump(&self, version: &mut Version) {
+        let release = match version.major == self.year && version.minor == self.month {
+            true => version.patch + 1,
+            false => 0,
+        };
+
+        *version = Version::new(self.year, self.month, release);
+    }
+}
@@ -1,9 +1,6 @@
-use std::{fs, path};
+use std::{env, fs, path, process};
 
-fn create_modinfo_file_v2() -> path::PathBuf {
-    std::fs::write(
-        "tests/fixtures/modinfo_v2.xml",
-        r#"
+const MODINFO_V2: &str = r#"
             <?xml version="1.0" encoding="UTF-8"?>
             <xml>
                 <Name value="SomeInternalName" />
@@ -13,19 +10,44 @@ fn create_modinfo_file_v2() -> path::PathBuf {
                 <Author value="Author Name" />
                 <Website value="https://example.org" />
             </xml>
-        "#,
-    )
-    .unwrap();
-
-    path::PathBuf::from("tests/fixtures/modinfo_v2.xml")
-}
+        "#;
 
+/// Writes a known good V2 modinfo to a temporary directory and returns its path
 pub fn setup() -> path::PathBuf {
-    create_modinfo_file_v2()
+    write_modinfo_v2(&temp_dir("setup"), "modinfo_v2.xml")
 }
 
+/// Removes the temporary directory created by `setup`
 pub fn cleanup() {
-    if path::Path::new("tests/fixtures/modinfo_v2.xml").exists() {
-        fs::remove_file("tests/fixtures/modinfo_v2.xml").unwrap();
+    remove_temp_dir(&temp_dir_path("setup"));
+}
+
+fn temp_dir_path(name: &str) -> path::PathBuf {
+    env::temp_dir().join(format!("modinfo_7dtd_{}_{}", name, process::id()))
+}
+
+/// Creates an empty, uniquely named directory under the system temp directory
+pub fn temp_dir(name: &str) -> path::PathBuf {
+    let dir = temp_dir_path(name);
+
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
+
+/// Writes a known good V2 modinfo into `dir` using the given `filename`
+pub fn write_modinfo_v2(dir: &path::Path, filename: &str) -> path::PathBuf {
+    let file = dir.join(filename);
+    fs::write(&file, MODINFO_V2).unwrap();
+
+    file
+}
+
+pub fn remove_temp_dir(dir: &path::Path) {
+    if dir.exists() {
+        fs::remove_dir_all(dir).unwrap();
     }
 }
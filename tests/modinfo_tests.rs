@@ -33,3 +33,380 @@ fn test_modinfo_parse() {
 
     fixtures::cleanup();
 }
+
+#[test]
+fn test_modinfo_parse_directory() {
+    let dir = fixtures::temp_dir("parse_directory");
+
+    assert!(matches!(
+        modinfo::parse(&dir),
+        Err(modinfo::ModinfoError::FsIsDirectory(path)) if path == dir
+    ));
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_parse_mod_dir() {
+    let dir = fixtures::temp_dir("parse_mod_dir");
+    let file = fixtures::write_modinfo_v2(&dir, "ModInfo.xml");
+
+    let modinfo = modinfo::parse_mod_dir(&dir).unwrap();
+
+    assert_eq!(
+        modinfo.get_value_for("name"),
+        Some(&Cow::from("SomeInternalName"))
+    );
+    assert_eq!(modinfo.get_file_path(), &file);
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_parse_mod_dir_no_modinfo() {
+    let dir = fixtures::temp_dir("parse_mod_dir_no_modinfo");
+
+    assert!(matches!(
+        modinfo::parse_mod_dir(&dir),
+        Err(modinfo::ModinfoError::NoModinfo)
+    ));
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_parse_mod_dir_not_a_directory() {
+    let dir = fixtures::temp_dir("parse_mod_dir_not_a_directory");
+    let file = fixtures::write_modinfo_v2(&dir, "ModInfo.xml");
+
+    assert!(matches!(
+        modinfo::parse_mod_dir(&file),
+        Err(modinfo::ModinfoError::FsDirNotFound(path)) if path == file
+    ));
+    assert!(matches!(
+        modinfo::parse_mod_dir(dir.join("missing")),
+        Err(modinfo::ModinfoError::FsDirNotFound(_))
+    ));
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_parse_mod_dir_prefers_exact_name() {
+    let dir = fixtures::temp_dir("parse_mod_dir_prefers_exact_name");
+    fixtures::write_modinfo_v2(&dir, "MODINFO.xml");
+    fixtures::write_modinfo_v2(&dir, "modinfo.xml");
+    let file = fixtures::write_modinfo_v2(&dir, "ModInfo.xml");
+
+    assert_eq!(modinfo::parse_mod_dir(&dir).unwrap().get_file_path(), &file);
+
+    std::fs::remove_file(&file).unwrap();
+
+    assert_eq!(
+        modinfo::parse_mod_dir(&dir).unwrap().get_file_path(),
+        &dir.join("MODINFO.xml")
+    );
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_write_atomic() {
+    let dir = fixtures::temp_dir("write_atomic");
+    let file = dir.join("ModInfo.xml");
+    let mut modinfo = modinfo::parse(fixtures::write_modinfo_v2(&dir, "source.xml")).unwrap();
+
+    modinfo.bump_version_minor();
+    modinfo.write_atomic(Some(&file)).unwrap();
+
+    let result = modinfo::parse(&file).unwrap();
+
+    assert_eq!(result.get_version().to_string(), "1.3.0".to_owned());
+    assert_eq!(
+        result.get_value_for("author"),
+        Some(&Cow::from("Author Name"))
+    );
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 2);
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_write_atomic_concurrent() {
+    let dir = fixtures::temp_dir("write_atomic_concurrent");
+    let file = dir.join("ModInfo.xml");
+    let modinfo = modinfo::parse(fixtures::write_modinfo_v2(&dir, "source.xml")).unwrap();
+
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| modinfo.write_atomic(Some(&file)).unwrap());
+        }
+    });
+
+    assert_eq!(
+        modinfo::parse(&file).unwrap().to_string(),
+        modinfo.to_string()
+    );
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 2);
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_write_atomic_missing_dir() {
+    let dir = fixtures::temp_dir("write_atomic_missing_dir").join("missing");
+    let modinfo = modinfo::Modinfo::new();
+
+    assert!(matches!(
+        modinfo.write_atomic(Some(&dir.join("ModInfo.xml"))),
+        Err(modinfo::ModinfoError::FsDirNotFound(path)) if path == dir
+    ));
+
+    fixtures::remove_temp_dir(dir.parent().unwrap());
+}
+
+#[test]
+fn test_modinfo_render_matches_write() {
+    let dir = fixtures::temp_dir("render");
+    let file = dir.join("ModInfo.xml");
+    let mut modinfo = modinfo::parse(fixtures::write_modinfo_v2(&dir, "source.xml")).unwrap();
+
+    modinfo.set_value_for("description", "Rendered before writing");
+    let rendered = modinfo.render().unwrap();
+    modinfo.write(Some(&file)).unwrap();
+
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), rendered);
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_parse_with_options_required_fields() {
+    let dir = fixtures::temp_dir("parse_required_fields");
+    let file = fixtures::write_modinfo_v2(&dir, "ModInfo.xml");
+    let xml = std::fs::read_to_string(&file).unwrap();
+    let xml: Vec<&str> = xml
+        .lines()
+        .filter(|line| !line.contains("<Description"))
+        .collect();
+    std::fs::write(&file, xml.join("\n")).unwrap();
+
+    let options = modinfo::ParseOptions {
+        required_fields: vec![modinfo::ModinfoField::Name, modinfo::ModinfoField::Author],
+        ..Default::default()
+    };
+    let modinfo = modinfo::parse_with_options(&file, &options).unwrap();
+    assert_eq!(modinfo.description(), None);
+
+    assert!(matches!(
+        modinfo::parse(&file),
+        Err(modinfo::ModinfoError::NoModinfoDescription)
+    ));
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_matches_file() {
+    let dir = fixtures::temp_dir("matches_file");
+    let file = dir.join("ModInfo.xml");
+    let mut modinfo = modinfo::parse(fixtures::write_modinfo_v2(&dir, "source.xml")).unwrap();
+
+    modinfo.write(Some(&file)).unwrap();
+    assert!(modinfo.matches_file(&file).unwrap());
+
+    modinfo.bump_version_patch();
+    assert!(!modinfo.matches_file(&file).unwrap());
+
+    assert!(modinfo.matches_file(&dir.join("missing.xml")).is_err());
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_write_creates_dirs() {
+    let dir = fixtures::temp_dir("write_creates_dirs");
+    let file = dir.join("newmod").join("ModInfo.xml");
+    let modinfo = modinfo::Modinfo::scaffold("NewMod", "Me");
+
+    modinfo.write(Some(&file)).unwrap();
+
+    let result = modinfo::parse(&file).unwrap();
+    assert_eq!(result.get_value_for("name"), Some(&Cow::from("NewMod")));
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_iter_dir_is_lazy() {
+    let dir = fixtures::temp_dir("iter_dir");
+    for name in ["AMod", "BMod", "CMod"] {
+        modinfo::Modinfo::scaffold(name, "Me")
+            .write(Some(&dir.join(name).join("ModInfo.xml")))
+            .unwrap();
+    }
+
+    let mut parsed = 0;
+    let found = modinfo::iter_dir(&dir)
+        .inspect(|_| parsed += 1)
+        .find(|(_, modinfo)| modinfo.as_ref().unwrap().name() == Some("BMod"));
+
+    assert_eq!(found.unwrap().0, dir.join("BMod"));
+    assert_eq!(parsed, 2);
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_iter_dir_missing_root() {
+    let dir = fixtures::temp_dir("iter_dir_missing_root").join("missing");
+    let results: Vec<_> = modinfo::iter_dir(&dir).collect();
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(
+        &results[0].1,
+        Err(modinfo::ModinfoError::FsDirNotFound(path)) if path == &dir
+    ));
+
+    fixtures::remove_temp_dir(dir.parent().unwrap());
+}
+
+#[test]
+fn test_modinfo_parse_with_warnings() {
+    let dir = fixtures::temp_dir("parse_with_warnings");
+    let file = fixtures::write_modinfo_v2(&dir, "ModInfo.xml");
+    let xml = std::fs::read_to_string(&file)
+        .unwrap()
+        .replace("https://example.org", "example dot org")
+        .replace("1.2.3", "1.2");
+    std::fs::write(&file, xml).unwrap();
+
+    let (modinfo, warnings) = modinfo::parse_with_warnings(&file).unwrap();
+
+    assert_eq!(modinfo.get_version().to_string(), "1.2.0");
+    assert_eq!(warnings.len(), 2);
+    assert!(warnings[0].contains("version '1.2'"));
+    assert!(warnings[1].contains("website 'example dot org'"));
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_parse_with_warnings_clean() {
+    let dir = fixtures::temp_dir("parse_with_warnings_clean");
+    let file = fixtures::write_modinfo_v2(&dir, "ModInfo.xml");
+
+    let (_, warnings) = modinfo::parse_with_warnings(&file).unwrap();
+
+    assert!(warnings.is_empty());
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_reload() {
+    let dir = fixtures::temp_dir("reload");
+    let file = fixtures::write_modinfo_v2(&dir, "ModInfo.xml");
+    let mut modinfo = modinfo::parse(&file).unwrap();
+
+    let xml = std::fs::read_to_string(&file)
+        .unwrap()
+        .replace("1.2.3", "2.0.0");
+    std::fs::write(&file, xml).unwrap();
+    modinfo.reload().unwrap();
+
+    assert_eq!(modinfo.get_version().to_string(), "2.0.0");
+    assert_eq!(modinfo.get_file_path(), &file);
+
+    std::fs::remove_file(&file).unwrap();
+    assert!(matches!(
+        modinfo.reload(),
+        Err(modinfo::ModinfoError::FsNotFound)
+    ));
+    assert_eq!(modinfo.get_version().to_string(), "2.0.0");
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_reload_without_path() {
+    assert!(matches!(
+        modinfo::Modinfo::new().reload(),
+        Err(modinfo::ModinfoError::FsNotFound)
+    ));
+}
+
+#[test]
+fn test_modinfo_parse_with_stats() {
+    let dir = fixtures::temp_dir("parse_with_stats");
+    let file = fixtures::write_modinfo_v2(&dir, "ModInfo.xml");
+    let (modinfo, stats) = modinfo::parse_with_stats(&file).unwrap();
+
+    assert_eq!(modinfo, modinfo::parse(&file).unwrap());
+    assert_eq!(stats.element_count, 6);
+    assert_eq!(
+        stats.bytes_read,
+        std::fs::read_to_string(&file).unwrap().len()
+    );
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_parse_with_stats_authors() {
+    let dir = fixtures::temp_dir("parse_with_stats_authors");
+    let file = dir.join("ModInfo.xml");
+    std::fs::write(
+        &file,
+        r#"<xml>
+            <Name value="SomeMod" />
+            <Version value="1.0.0" />
+            <Description value="A mod" />
+            <Authors><Author value="A" /><Author value="B" /></Authors>
+        </xml>"#,
+    )
+    .unwrap();
+
+    let (_, stats) = modinfo::parse_with_stats(&file).unwrap();
+
+    assert_eq!(stats.element_count, 5);
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_write_with_backup() {
+    let dir = fixtures::temp_dir("write_with_backup");
+    let file = fixtures::write_modinfo_v2(&dir, "ModInfo.xml");
+    let original = std::fs::read_to_string(&file).unwrap();
+    let mut modinfo = modinfo::parse(&file).unwrap();
+
+    modinfo.bump_version_major();
+    modinfo.write_with_backup(None).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(dir.join("ModInfo.xml.bak")).unwrap(),
+        original
+    );
+    assert_eq!(
+        modinfo::parse(&file).unwrap().get_version().to_string(),
+        "2.0.0"
+    );
+
+    fixtures::remove_temp_dir(&dir);
+}
+
+#[test]
+fn test_modinfo_write_with_backup_new_file() {
+    let dir = fixtures::temp_dir("write_with_backup_new_file");
+    let file = dir.join("ModInfo.xml");
+
+    modinfo::Modinfo::scaffold("NewMod", "Me")
+        .write_with_backup(Some(&file))
+        .unwrap();
+
+    assert!(file.exists());
+    assert!(!dir.join("ModInfo.xml.bak").exists());
+
+    fixtures::remove_temp_dir(&dir);
+}